@@ -0,0 +1,871 @@
+//! Convert the guide's Markdown files to HTML at compile time, and emit them as the `book`
+//! module, along with the section lists declared in each version's `guide.toml`. The HTML
+//! is only compiled into native builds, which prerender it into files the wasm app fetches.
+//! Syntax highlighting is done here, on the native host, since `syntect` doesn't work on the
+//! `wasm32-unknown-unknown` target. The output uses the same classes Pandoc did, so
+//! `syntax_style.css` applies unchanged.
+//!
+//! It also builds the inverted index used by the search box, as `search_index.rs`, and
+//! parses `markdown/changelog.md` into typed releases, as `changelog.rs`. The newest
+//! guide's Rust snippets become doctests, in `snippets.rs`, so they're compiled by
+//! `cargo test`, and every guide's links are listed in `links.rs`, for `src/links.rs` to
+//! check.
+
+use std::{collections::BTreeMap, env, fs, path::Path};
+
+use pulldown_cmark::{
+    escape::escape_html, html, CodeBlockKind, CowStr, Event, HeadingLevel, Options, Parser, Tag,
+};
+use serde::Deserialize;
+use syntect::parsing::{ParseState, Scope, ScopeStack, SyntaxSet};
+
+#[path = "src/search/tokenize.rs"]
+mod tokenize;
+
+/// How much more a term counts towards a section's ranking when it appears in the section's
+/// title, or one of its headings, than in its body.
+const TITLE_WEIGHT: u32 = 10;
+const HEADING_WEIGHT: u32 = 5;
+
+/// The contents of a version's `guide.toml`.
+#[derive(Deserialize)]
+struct Manifest {
+    section: Vec<SectionEntry>,
+}
+
+#[derive(Deserialize)]
+struct SectionEntry {
+    title: String,
+    slug: String,
+    /// The Markdown file, relative to the version's directory.
+    file: String,
+    /// Sections sharing a group are listed under a common heading in the menu.
+    group: String,
+}
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let md_dir = Path::new(&manifest_dir).join("markdown");
+
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", md_dir.display());
+
+    let changelog_path = md_dir.join("changelog.md");
+    println!("cargo:rerun-if-changed={}", changelog_path.display());
+    let releases = parse_changelog(
+        &fs::read_to_string(&changelog_path).expect("Problem reading the changelog"),
+    );
+    // The newest release is the version the site documents, and is shown on the page.
+    let latest = releases.first().expect("The changelog has no releases");
+    check_locked_version(Path::new(&manifest_dir), &latest.version);
+
+    // Each version of the guide is a directory with its own `guide.toml`, eg `markdown/0.4`.
+    let mut versions: Vec<String> = fs::read_dir(&md_dir)
+        .expect("Problem reading the markdown directory")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.join("guide.toml").exists())
+        .map(|path| path.file_name().unwrap().to_str().unwrap().to_string())
+        .collect();
+    // Newest first.
+    versions.sort_by_key(|v| std::cmp::Reverse(version_key(v)));
+
+    let syntax_set = SyntaxSet::load_defaults_newlines();
+    let mut book =
+        String::from("// Generated by build.rs from the `markdown` directory. Don't edit.\n");
+    let mut search_index = String::from(
+        "// Generated by build.rs from the guide's sections. Don't edit.\n\n\
+         /// A version's index: each term, sorted, with the sections (by index into the\n\
+         /// version's `sections`) it appears in, and its weighted count in each.\n\
+         pub type Index = &'static [(&'static str, &'static [(usize, u32)])];\n\n\
+         /// Each version's index, in the order of `book::VERSIONS`.\n\
+         pub const INDEXES: &[Index] = &[\n",
+    );
+    let mut version_list = String::new();
+    let mut snippets = Vec::new();
+    let mut links = String::from(
+        "// Generated by build.rs from the guide's links. Don't edit.\n\n\
+         const LINKS: &[Link] = &[\n",
+    );
+
+    for (i, version) in versions.iter().enumerate() {
+        // Link to the API docs of the newest patch release of this version.
+        let docs_version = releases
+            .iter()
+            .find(|r| r.version.starts_with(&format!("{}.", version)))
+            .map_or(version.as_str(), |r| r.version.as_str());
+        let module = format!("v{}", module_name(version));
+
+        let guide = build_guide(&md_dir.join(version), docs_version, &syntax_set);
+        // Older guides document older versions of Seed than we build against.
+        if i == 0 {
+            snippets = guide.snippets;
+        }
+        links.push_str(&guide.links);
+        book.push_str(&format!(
+            "\npub mod {} {{\n    use super::{{Heading, Section}};\n{}}}\n",
+            module, guide.code
+        ));
+        version_list.push_str(&format!(
+            "    Version {{\n        name: {:?},\n        sections: {}::SECTIONS,\n    }},\n",
+            version, module
+        ));
+
+        search_index.push_str("    &[\n");
+        for (term, postings) in guide.index {
+            let postings: Vec<_> = postings.into_iter().collect();
+            search_index.push_str(&format!("        ({:?}, &{:?}),\n", term, postings));
+        }
+        search_index.push_str("    ],\n");
+    }
+
+    book.push_str(&format!(
+        "\n/// Every version of the guide, newest first.\npub const VERSIONS: &[Version] = &[\n{}];\n",
+        version_list
+    ));
+    search_index.push_str("];\n");
+
+    fs::write(Path::new(&out_dir).join("book.rs"), book).expect("Problem writing the book module");
+    fs::write(Path::new(&out_dir).join("search_index.rs"), search_index)
+        .expect("Problem writing the search index");
+    links.push_str("];\n");
+    fs::write(Path::new(&out_dir).join("links.rs"), links).expect("Problem writing the links");
+    fs::write(Path::new(&out_dir).join("snippets.rs"), snippet_tests(&snippets))
+        .expect("Problem writing the snippet tests");
+
+    let mut changelog = String::from(
+        "// Generated by build.rs from `markdown/changelog.md`. Don't edit.\n\n\
+         /// Every release, newest first.\n\
+         pub const RELEASES: &[Release] = &[\n",
+    );
+    for release in &releases {
+        let entries: String = release
+            .entries
+            .iter()
+            .map(|(text, breaking)| {
+                format!(
+                    "            Entry {{ text: {:?}, breaking: {} }},\n",
+                    text, breaking
+                )
+            })
+            .collect();
+        changelog.push_str(&format!(
+            "    Release {{\n        version: {:?},\n        date: {:?},\n        entries: &[\n{}        ],\n    }},\n",
+            release.version, release.date, entries
+        ));
+    }
+    changelog.push_str("];\n");
+    changelog.push_str(&format!(
+        "\n/// The newest release; the version of Seed this site documents.\n\
+         pub const LATEST_VERSION: &str = {:?};\n\
+         pub const LATEST_RELEASE_DATE: Option<&str> = {:?};\n",
+        latest.version, latest.date
+    ));
+
+    fs::write(Path::new(&out_dir).join("changelog.rs"), changelog)
+        .expect("Problem writing the changelog");
+}
+
+/// Term -> (section index -> weighted occurrence count)
+type Index = BTreeMap<String, BTreeMap<usize, u32>>;
+
+/// One version of the guide, generated from its directory.
+struct Guide {
+    /// The contents of its module in `book.rs`.
+    code: String,
+    index: Index,
+    snippets: Vec<Snippet>,
+    /// Its entries in `links.rs`.
+    links: String,
+}
+
+/// Generate the modules for one version of the guide, from the `guide.toml` in its
+/// directory, along with its search index. `docs_version` is the version API links
+/// point at, eg `0.4.2`.
+fn build_guide(dir: &Path, docs_version: &str, syntax_set: &SyntaxSet) -> Guide {
+    let version = dir.file_name().unwrap().to_string_lossy();
+    let manifest_path = dir.join("guide.toml");
+    println!("cargo:rerun-if-changed={}", manifest_path.display());
+    let manifest: Manifest =
+        toml::from_str(&fs::read_to_string(&manifest_path).expect("Problem reading guide.toml"))
+            .expect("Problem parsing guide.toml");
+
+    let mut code = String::new();
+    let mut sections = String::new();
+    let mut index = Index::new();
+    let mut snippets = Vec::new();
+    let mut links = String::new();
+
+    for (i, entry) in manifest.section.iter().enumerate() {
+        let path = dir.join(&entry.file);
+        println!("cargo:rerun-if-changed={}", path.display());
+        let name = module_name(&entry.file);
+        let source = fs::read_to_string(&path)
+            .unwrap_or_else(|_| panic!("Problem reading {}", path.display()));
+        // Snippets and links are found in the source, so their line numbers are its.
+        snippets.extend(rust_snippets(&path, &source));
+        let links_found = links_of(&source, docs_version);
+        let markdown = expand_includes(&path, &source);
+
+        let (html, headings) = to_html(&markdown, docs_version, syntax_set);
+        for (line, url) in links_found {
+            links.push_str(&format!(
+                "    Link {{ version: {:?}, slug: {:?}, file: {:?}, line: {}, url: {:?} }},\n",
+                version,
+                entry.slug,
+                relative_path(&path),
+                line,
+                url
+            ));
+        }
+        code.push_str(&format!(
+            "\n    #[cfg(not(target_arch = \"wasm32\"))]\n    pub mod {} {{\n        pub fn text() -> String {{\n            {:?}.into()\n        }}\n    }}\n",
+            name, html
+        ));
+
+        let headings: String = headings
+            .iter()
+            .map(|(level, id, title)| {
+                format!(
+                    "                Heading {{ level: {}, id: {:?}, title: {:?} }},\n",
+                    level, id, title
+                )
+            })
+            .collect();
+        let (body, heading_text) = plain_text_of(&markdown);
+        sections.push_str(&format!(
+            "        Section {{\n            title: {:?},\n            slug: {:?},\n            group: {:?},\n            #[cfg(not(target_arch = \"wasm32\"))]\n            text: {}::text,\n            description: {:?},\n            plain_text: {:?},\n            headings: &[\n{}            ],\n        }},\n",
+            entry.title,
+            entry.slug,
+            entry.group,
+            name,
+            first_paragraph(&markdown),
+            body,
+            headings
+        ));
+
+        let weighted = [
+            (entry.title.as_str(), TITLE_WEIGHT),
+            (heading_text.as_str(), HEADING_WEIGHT),
+            (body.as_str(), 1),
+        ];
+        for (text, weight) in &weighted {
+            for term in tokenize::tokenize(text) {
+                *index.entry(term).or_default().entry(i).or_default() += weight;
+            }
+        }
+    }
+
+    code.push_str(&format!(
+        "\n    /// This version's sections, in reading order.\n    pub const SECTIONS: &[Section] = &[\n{}    ];\n",
+        sections
+    ));
+    Guide {
+        code,
+        index,
+        snippets,
+        links,
+    }
+}
+
+/// Expand mdBook-style `{{#include path}}` lines, relative to the Markdown file, into the
+/// file's contents; or with `{{#include path:name}}`, into the lines between its
+/// `// ANCHOR: name` and `// ANCHOR_END: name` comments. Anchor comments are left out.
+fn expand_includes(path: &Path, markdown: &str) -> String {
+    let mut expanded = String::new();
+    for line in markdown.lines() {
+        let spec = line
+            .trim()
+            .strip_prefix("{{#include ")
+            .and_then(|rest| rest.strip_suffix("}}"));
+        let spec = match spec {
+            Some(spec) => spec.trim(),
+            None => {
+                expanded.push_str(line);
+                expanded.push('\n');
+                continue;
+            }
+        };
+
+        let (file, anchor) = match spec.rsplitn(2, ':').collect::<Vec<_>>()[..] {
+            [anchor, file] => (file, Some(anchor)),
+            _ => (spec, None),
+        };
+        let included = path.parent().unwrap().join(file);
+        println!("cargo:rerun-if-changed={}", included.display());
+        let code = fs::read_to_string(&included).unwrap_or_else(|_| {
+            panic!("Problem reading {}, included by {}", included.display(), path.display())
+        });
+
+        let mut in_anchor = anchor.is_none();
+        let mut found = anchor.is_none();
+        for code_line in code.lines() {
+            let comment = code_line.trim();
+            if let Some(name) = comment.strip_prefix("// ANCHOR: ") {
+                if Some(name) == anchor {
+                    in_anchor = true;
+                    found = true;
+                }
+            } else if let Some(name) = comment.strip_prefix("// ANCHOR_END: ") {
+                if Some(name) == anchor {
+                    in_anchor = false;
+                }
+            } else if in_anchor {
+                expanded.push_str(code_line);
+                expanded.push('\n');
+            }
+        }
+        assert!(
+            found,
+            "{} includes `{}`, which has no anchor `{}`",
+            path.display(),
+            file,
+            anchor.unwrap_or("")
+        );
+    }
+    expanded
+}
+
+/// A path relative to the crate, for messages, eg `markdown/0.4/routing.md`.
+fn relative_path(path: &Path) -> String {
+    path.strip_prefix(env::var("CARGO_MANIFEST_DIR").unwrap())
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+/// The line number of a byte offset in a document.
+fn line_of(text: &str, offset: usize) -> usize {
+    text[..offset].matches('\n').count() + 1
+}
+
+/// Each link's line, and destination as shown on the site, with docs.rs links pointing at
+/// `docs_version`.
+fn links_of(markdown: &str, docs_version: &str) -> Vec<(usize, String)> {
+    Parser::new(markdown)
+        .into_offset_iter()
+        .filter_map(|(event, range)| match event {
+            Event::Start(Tag::Link(_, dest, _)) => Some((
+                line_of(markdown, range.start),
+                rewrite_version(dest, docs_version).into_string(),
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+/// A Rust code block from the guide.
+struct Snippet {
+    /// Where it is, eg `markdown/0.4/routing.md` and `22`.
+    file: String,
+    line: usize,
+    /// From its fence, after the language, eg `ignore` for ` ```rust,ignore `.
+    annotations: Vec<String>,
+    code: String,
+}
+
+fn rust_snippets(path: &Path, markdown: &str) -> Vec<Snippet> {
+    let file = relative_path(path);
+
+    let mut snippets = Vec::new();
+    let mut parser = Parser::new(markdown).into_offset_iter();
+    while let Some((event, range)) = parser.next() {
+        if let Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) = event {
+            let mut tokens = info.split(',').map(str::trim);
+            if tokens.next() != Some("rust") {
+                continue;
+            }
+            let annotations = tokens.map(String::from).collect();
+            let code = parser
+                .by_ref()
+                .take_while(|(e, _)| !matches!(e, Event::End(Tag::CodeBlock(_))))
+                .filter_map(|(e, _)| match e {
+                    Event::Text(text) => Some(text.into_string()),
+                    _ => None,
+                })
+                .collect();
+
+            snippets.push(Snippet {
+                file: file.clone(),
+                line: line_of(markdown, range.start),
+                annotations,
+                code,
+            });
+        }
+    }
+    snippets
+}
+
+/// Each snippet as a doctest, on an empty module named after where it is. They're compiled
+/// but not run, since they need a browser. Snippets that are only fragments opt out with
+/// ` ```rust,ignore `; others can include setup in hidden `# ` lines, as in rustdoc.
+fn snippet_tests(snippets: &[Snippet]) -> String {
+    let mut code = String::from(
+        "// Generated by build.rs from the newest guide's Rust snippets. Don't edit.\n",
+    );
+
+    for snippet in snippets {
+        let mode = if snippet.annotations.iter().any(|a| a == "ignore") {
+            "ignore"
+        } else {
+            "no_run"
+        };
+        let mut test = format!("```{}\n# #![allow(unused)]\n", mode);
+        if !snippet.code.contains("extern crate seed") {
+            test.push_str("# #[macro_use]\n# extern crate seed;\n");
+        }
+        test.push_str("# use seed::prelude::*;\n");
+        test.push_str(&snippet.code);
+        // Snippets are items, like a module's, unless they have their own `main`.
+        if !snippet.code.contains("fn main") {
+            test.push_str("# fn main() {}\n");
+        }
+        test.push_str("```\n");
+
+        let name = Path::new(&snippet.file)
+            .file_stem()
+            .map_or("snippet".into(), |stem| module_name(&stem.to_string_lossy()));
+        code.push_str(&format!(
+            "\n/// `{}`, line {}\n///\n#[doc = {:?}]\npub mod {}_line_{} {{}}\n",
+            snippet.file, snippet.line, test, name, snippet.line
+        ));
+    }
+    code
+}
+
+/// A version's numeric parts, for sorting, eg `0.10` -> `[0, 10]`.
+fn version_key(version: &str) -> Vec<u32> {
+    version
+        .split('.')
+        .filter_map(|part| part.parse().ok())
+        .collect()
+}
+
+/// Warn if the `seed` version resolved in `Cargo.lock` isn't the newest release in the
+/// changelog, since the guide's API links would point at a different version than we
+/// build against.
+fn check_locked_version(manifest_dir: &Path, version: &str) {
+    #[derive(Deserialize)]
+    struct Lockfile {
+        package: Vec<Package>,
+    }
+    #[derive(Deserialize)]
+    struct Package {
+        name: String,
+        version: String,
+    }
+
+    let lock_path = manifest_dir.join("Cargo.lock");
+    println!("cargo:rerun-if-changed={}", lock_path.display());
+    let lockfile: Option<Lockfile> = fs::read_to_string(&lock_path)
+        .ok()
+        .and_then(|lock| toml::from_str(&lock).ok());
+
+    let locked = lockfile.and_then(|lock| lock.package.into_iter().find(|p| p.name == "seed"));
+    if let Some(locked) = locked {
+        if locked.version != version {
+            println!(
+                "cargo:warning=Cargo.lock resolves seed {}, but the changelog's newest release is {}",
+                locked.version, version
+            );
+        }
+    }
+}
+
+/// A release parsed from the changelog. Entries are HTML, with whether they're breaking.
+struct Release {
+    version: String,
+    date: Option<String>,
+    entries: Vec<(String, bool)>,
+}
+
+/// Parse the changelog: each `## v0.4.2 (2019-11-05)` heading starts a release, and each
+/// list item under it is an entry. Entries marked "(Breaking)" are flagged, and the marker
+/// removed from their text.
+fn parse_changelog(markdown: &str) -> Vec<Release> {
+    let mut releases: Vec<Release> = Vec::new();
+    let mut parser = Parser::new(markdown);
+
+    while let Some(event) = parser.next() {
+        match event {
+            Event::Start(Tag::Heading(HeadingLevel::H2, ..)) => {
+                let inner: Vec<_> = parser
+                    .by_ref()
+                    .take_while(|e| !matches!(e, Event::End(Tag::Heading(..))))
+                    .collect();
+                let heading = plain_text(&inner);
+                let mut parts = heading.splitn(2, ' ');
+
+                releases.push(Release {
+                    version: parts
+                        .next()
+                        .unwrap()
+                        .trim_start_matches(['v', 'V'])
+                        .to_string(),
+                    date: parts
+                        .next()
+                        .map(|date| date.trim_matches(['(', ')', ' ']))
+                        .map(String::from),
+                    entries: Vec::new(),
+                });
+            }
+            Event::Start(Tag::Item) => {
+                let mut breaking = false;
+                let inner: Vec<_> = parser
+                    .by_ref()
+                    .take_while(|e| !matches!(e, Event::End(Tag::Item)))
+                    .map(|e| match e {
+                        Event::Text(text) => {
+                            let (text, marked) = remove_breaking_marker(&text);
+                            breaking |= marked;
+                            Event::Text(text.into())
+                        }
+                        e => e,
+                    })
+                    .collect();
+
+                let mut html = String::new();
+                html::push_html(&mut html, inner.into_iter());
+                releases
+                    .last_mut()
+                    .expect("Changelog entries must follow a release heading")
+                    .entries
+                    .push((html.trim().to_string(), breaking));
+            }
+            _ => (),
+        }
+    }
+    releases
+}
+
+/// Strip a "(Breaking)" marker, in any case, from text; returns whether there was one.
+fn remove_breaking_marker(text: &str) -> (String, bool) {
+    const MARKER: &str = "(breaking)";
+    match text.to_ascii_lowercase().find(MARKER) {
+        Some(i) => {
+            let stripped = format!("{}{}", &text[..i], &text[i + MARKER.len()..]);
+            (stripped.trim_end().to_string(), true)
+        }
+        None => (text.to_string(), false),
+    }
+}
+
+/// A valid module name for a Markdown file or version, eg `release_and_debugging.md` ->
+/// `release_and_debugging`, or `0.4` -> `0_4`.
+fn module_name(file: &str) -> String {
+    file.trim_end_matches(".md")
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+/// The text of a Markdown document without its markup, for searching: its body (excluding
+/// code blocks, which don't make useful snippets), and its headings.
+fn plain_text_of(markdown: &str) -> (String, String) {
+    let mut body = String::new();
+    let mut headings = String::new();
+    let mut in_heading = false;
+    let mut in_code_block = false;
+
+    for event in Parser::new_ext(markdown, Options::ENABLE_SMART_PUNCTUATION) {
+        match event {
+            Event::Start(Tag::Heading(..)) => in_heading = true,
+            Event::End(Tag::Heading(..)) => {
+                in_heading = false;
+                headings.push(' ');
+            }
+            Event::Start(Tag::CodeBlock(_)) => in_code_block = true,
+            Event::End(Tag::CodeBlock(_)) => in_code_block = false,
+            Event::Text(text) | Event::Code(text) if !in_code_block => {
+                if in_heading {
+                    headings.push_str(&text);
+                } else {
+                    body.push_str(&text);
+                }
+            }
+            Event::SoftBreak
+            | Event::HardBreak
+            | Event::End(Tag::Paragraph)
+            | Event::End(Tag::Item)
+                if !body.ends_with(' ') =>
+            {
+                body.push(' ')
+            }
+            _ => (),
+        }
+    }
+    (body.trim().to_string(), headings.trim().to_string())
+}
+
+/// The text of a Markdown document's first paragraph, without markup; empty if it hasn't
+/// got one.
+fn first_paragraph(markdown: &str) -> String {
+    let mut text = String::new();
+    let mut in_paragraph = false;
+
+    for event in Parser::new_ext(markdown, Options::ENABLE_SMART_PUNCTUATION) {
+        match event {
+            Event::Start(Tag::Paragraph) => in_paragraph = true,
+            Event::End(Tag::Paragraph) if !text.trim().is_empty() => break,
+            Event::End(Tag::Paragraph) => in_paragraph = false,
+            Event::Text(t) | Event::Code(t) if in_paragraph => text.push_str(&t),
+            Event::SoftBreak | Event::HardBreak if in_paragraph => text.push(' '),
+            _ => (),
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// A heading's level, `id` and text.
+type Heading = (u32, String, String);
+
+/// Render a Markdown document to an HTML fragment, in the shape Pandoc used to produce:
+/// headings get `id`s for linking, and code blocks are highlighted. Returns the headings
+/// too, for the table of contents.
+fn to_html(markdown: &str, version: &str, syntax_set: &SyntaxSet) -> (String, Vec<Heading>) {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_SMART_PUNCTUATION);
+
+    let mut events = Vec::new();
+    let mut heading_ids = Vec::new();
+    let mut headings = Vec::new();
+    let mut code_block_count = 0;
+
+    let mut parser = Parser::new_ext(markdown, options);
+    while let Some(event) = parser.next() {
+        match event {
+            Event::Start(Tag::Heading(level, ..)) => {
+                // Buffer the heading's contents, so we can build its id from the text.
+                let inner: Vec<_> = parser
+                    .by_ref()
+                    .take_while(|e| !matches!(e, Event::End(Tag::Heading(..))))
+                    .collect();
+                let text = plain_text(&inner);
+                let id = unique_id(heading_id(&text), &mut heading_ids);
+                headings.push((level as u32, id.clone(), text));
+
+                let mut inner_html = String::new();
+                html::push_html(&mut inner_html, inner.into_iter());
+
+                events.push(Event::Html(
+                    format!("<{0} id=\"{1}\">{2}</{0}>\n", level, id, inner_html).into(),
+                ));
+            }
+            Event::Start(Tag::CodeBlock(kind)) => {
+                let lang = match kind {
+                    CodeBlockKind::Fenced(info) => {
+                        info.split(',').next().unwrap_or("").trim().to_string()
+                    }
+                    CodeBlockKind::Indented => String::new(),
+                };
+                let code: String = parser
+                    .by_ref()
+                    .take_while(|e| !matches!(e, Event::End(Tag::CodeBlock(_))))
+                    .filter_map(|e| match e {
+                        Event::Text(text) => Some(text.into_string()),
+                        _ => None,
+                    })
+                    .collect();
+
+                code_block_count += 1;
+                events.push(Event::Html(
+                    code_block(&code, &lang, code_block_count, syntax_set).into(),
+                ));
+            }
+            Event::Start(Tag::Link(link_type, dest, title)) => {
+                events.push(Event::Start(Tag::Link(
+                    link_type,
+                    rewrite_version(dest, version),
+                    title,
+                )));
+            }
+            _ => events.push(event),
+        }
+    }
+
+    let mut result = String::new();
+    html::push_html(&mut result, events.into_iter());
+    (result, headings)
+}
+
+/// Point links to Seed's API docs at the current version.
+fn rewrite_version<'a>(dest: CowStr<'a>, version: &str) -> CowStr<'a> {
+    const PREFIX: &str = "https://docs.rs/seed/";
+    if !dest.starts_with(PREFIX) {
+        return dest;
+    }
+    let rest = &dest[PREFIX.len()..];
+    match rest.find('/') {
+        Some(i) if rest[..i].starts_with("0.") => {
+            format!("{}{}{}", PREFIX, version, &rest[i..]).into()
+        }
+        _ => dest,
+    }
+}
+
+/// The text content of a run of inline events, eg for building heading ids.
+fn plain_text(events: &[Event]) -> String {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            Event::Text(text) | Event::Code(text) => Some(text.as_ref()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Create an `id` from heading text, the way Pandoc does: lowercase, with spaces
+/// replaced by hyphens, and punctuation other than `_`, `-` and `.` removed.
+fn heading_id(text: &str) -> String {
+    let id: String = text
+        .trim()
+        .chars()
+        .skip_while(|c| !c.is_alphabetic())
+        .filter_map(|c| match c {
+            c if c.is_whitespace() => Some('-'),
+            c if c.is_alphanumeric() || c == '_' || c == '-' || c == '.' => Some(c),
+            _ => None,
+        })
+        .flat_map(char::to_lowercase)
+        .collect();
+
+    if id.is_empty() {
+        "section".into()
+    } else {
+        id
+    }
+}
+
+/// Add a numeric suffix to ids we've already used in this document.
+fn unique_id(id: String, used: &mut Vec<String>) -> String {
+    let mut candidate = id.clone();
+    let mut i = 1;
+    while used.contains(&candidate) {
+        candidate = format!("{}-{}", id, i);
+        i += 1;
+    }
+    used.push(candidate.clone());
+    candidate
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::new();
+    escape_html(&mut escaped, text).unwrap();
+    escaped
+}
+
+/// Highlight a code block, wrapping it in the `sourceCode` elements `syntax_style.css` expects.
+fn code_block(code: &str, lang: &str, index: usize, syntax_set: &SyntaxSet) -> String {
+    // Rust snippets' hidden lines are setup for compiling them; see `snippet_tests`.
+    let visible: String;
+    let code = if lang == "rust" {
+        visible = code
+            .split_inclusive('\n')
+            .filter(|line| {
+                let line = line.trim();
+                line != "#" && !line.starts_with("# ")
+            })
+            .collect();
+        &visible
+    } else {
+        code
+    };
+
+    let body = match syntax_set.find_syntax_by_token(lang) {
+        Some(syntax) if !lang.is_empty() => {
+            let mut state = ParseState::new(syntax);
+            let mut stack = ScopeStack::new();
+            let mut spans: Vec<(Option<&'static str>, String)> = Vec::new();
+
+            for line in code.split_inclusive('\n') {
+                let ops = state
+                    .parse_line(line, syntax_set)
+                    .expect("Problem highlighting code");
+                let mut start = 0;
+                for (end, op) in ops {
+                    push_span(&mut spans, &line[start..end], &stack);
+                    stack.apply(&op).unwrap();
+                    start = end;
+                }
+                push_span(&mut spans, &line[start..], &stack);
+            }
+
+            spans
+                .into_iter()
+                .map(|(class, text)| match class {
+                    Some(class) => format!("<span class=\"{}\">{}</span>", class, escape(&text)),
+                    None => escape(&text),
+                })
+                .collect()
+        }
+        _ => escape(code),
+    };
+
+    // A language badge, and a copy button; the app handles clicks on it.
+    let badge = if lang.is_empty() {
+        String::new()
+    } else {
+        format!("<span class=\"code-lang\">{}</span>", escape(lang))
+    };
+    format!(
+        "<div class=\"sourceCode\" id=\"cb{0}\"><pre class=\"sourceCode {1}\"><code class=\"sourceCode {1}\">{2}</code></pre>{3}<button class=\"code-copy\" title=\"Copy to clipboard\">Copy</button></div>\n",
+        index,
+        lang,
+        body.trim_end_matches('\n'),
+        badge
+    )
+}
+
+/// Add highlighted text, merging it with the previous span if their classes match.
+fn push_span(spans: &mut Vec<(Option<&'static str>, String)>, text: &str, stack: &ScopeStack) {
+    if text.is_empty() {
+        return;
+    }
+    let class = if text.trim().is_empty() {
+        None
+    } else {
+        pandoc_class(stack)
+    };
+
+    match spans.last_mut() {
+        Some((last_class, last_text)) if *last_class == class => last_text.push_str(text),
+        _ => spans.push((class, text.to_string())),
+    }
+}
+
+/// Map the innermost `syntect` scope we recognize to the short class Pandoc would have used.
+fn pandoc_class(stack: &ScopeStack) -> Option<&'static str> {
+    const CLASSES: &[(&str, &str)] = &[
+        ("comment.line.documentation", "do"),
+        ("comment.block.documentation", "do"),
+        ("comment", "co"),
+        ("constant.character", "ch"),
+        ("string", "st"),
+        ("constant.numeric.float", "fl"),
+        ("constant.numeric", "dv"),
+        ("constant.language", "cn"),
+        ("constant.other", "cn"),
+        ("keyword.operator", "op"),
+        ("keyword", "kw"),
+        ("storage.type.numeric", "dt"),
+        ("storage", "kw"),
+        ("meta.annotation", "at"),
+        ("support.macro", "pp"),
+        ("entity.name.macro", "pp"),
+        ("meta.path", "pp"),
+        ("entity.name.function", "fu"),
+        ("support.function", "fu"),
+        ("entity.name", "dt"),
+        ("support.type", "dt"),
+        ("punctuation.section", "op"),
+    ];
+
+    stack.as_slice().iter().rev().find_map(|scope| {
+        CLASSES
+            .iter()
+            .find(|(prefix, _)| Scope::new(prefix).unwrap().is_prefix_of(*scope))
+            .map(|(_, class)| *class)
+    })
+}
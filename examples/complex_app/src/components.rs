@@ -0,0 +1,35 @@
+//! Views shared between pages. They're generic over the message type, and take the
+//! messages to send as arguments, so any page can use them with its own `Msg`.
+
+use seed::prelude::*;
+
+// ANCHOR: counter
+/// A count, with buttons to change it.
+pub fn counter<Ms: Clone + 'static>(
+    label: &str,
+    count: i32,
+    on_decrement: Ms,
+    on_increment: Ms,
+) -> Node<Ms> {
+    div![
+        class!["counter"],
+        span![label],
+        button!["-", simple_ev(Ev::Click, on_decrement)],
+        span![count.to_string()],
+        button!["+", simple_ev(Ev::Click, on_increment)]
+    ]
+}
+// ANCHOR_END: counter
+
+/// Links to each page, by title and URL; `current` is the open page's URL.
+pub fn nav<Ms: Clone + 'static>(pages: &[(&str, &str)], current: &str) -> Node<Ms> {
+    nav![pages.iter().map(|(title, url)| {
+        a![
+            attrs! {
+                At::Href => url;
+                At::Class => if *url == current {"nav-current"} else {"nav"};
+            },
+            title
+        ]
+    })]
+}
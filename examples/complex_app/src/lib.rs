@@ -0,0 +1,143 @@
+//! A small app with a module per page, each with its own `Model`, `Msg`, `update` and
+//! `view`, and components shared between them. The guide's Complex apps section is drawn
+//! from it; the `ANCHOR` comments mark the excerpts it includes.
+
+#[macro_use]
+extern crate seed;
+use seed::prelude::*;
+
+mod components;
+mod page;
+
+// ANCHOR: model
+/// The page that's showing, with its model. Each page's state is created when it's opened,
+/// and dropped when the reader leaves it.
+enum Page {
+    Counters(page::counters::Model),
+    Clock(page::clock::Model),
+}
+
+struct Model {
+    page: Page,
+}
+
+#[derive(Clone, Debug)]
+enum Msg {
+    ChangePage(Route),
+    Counters(page::counters::Msg),
+    Clock(page::clock::Msg),
+}
+// ANCHOR_END: model
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Route {
+    Counters,
+    Clock,
+}
+
+impl Route {
+    fn from_url(url: &seed::Url) -> Option<Self> {
+        match url.path.first().map(String::as_str) {
+            None | Some("") => Some(Route::Counters),
+            Some("clock") => Some(Route::Clock),
+            _ => None,
+        }
+    }
+
+    fn to_url(self) -> &'static str {
+        match self {
+            Route::Counters => "/",
+            Route::Clock => "/clock",
+        }
+    }
+}
+
+impl Page {
+    fn new(route: Route) -> Self {
+        match route {
+            Route::Counters => Page::Counters(page::counters::Model::default()),
+            Route::Clock => Page::Clock(page::clock::Model::default()),
+        }
+    }
+
+    fn route(&self) -> Route {
+        match self {
+            Page::Counters(_) => Route::Counters,
+            Page::Clock(_) => Route::Clock,
+        }
+    }
+}
+
+// ANCHOR: update
+fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
+    match (msg, &mut model.page) {
+        (Msg::ChangePage(route), _) => model.page = Page::new(route),
+        (Msg::Counters(msg), Page::Counters(model)) => page::counters::update(msg, model),
+        // The clock's update gets orders for its own messages, which `proxy` wraps in ours.
+        (Msg::Clock(msg), Page::Clock(model)) => {
+            page::clock::update(msg, model, &mut orders.proxy(Msg::Clock))
+        }
+        // Eg a tick that arrives after the clock page's been left.
+        _ => {
+            orders.skip();
+        }
+    }
+}
+// ANCHOR_END: update
+
+// ANCHOR: view
+fn view(model: &Model) -> Node<Msg> {
+    let page = match &model.page {
+        Page::Counters(model) => page::counters::view(model).map_message(Msg::Counters),
+        Page::Clock(model) => page::clock::view(model).map_message(Msg::Clock),
+    };
+
+    div![
+        components::nav(
+            &[("Counters", Route::Counters.to_url()), ("Clock", Route::Clock.to_url())],
+            model.page.route().to_url(),
+        ),
+        page
+    ]
+}
+// ANCHOR_END: view
+
+fn routes(url: seed::Url) -> Option<Msg> {
+    Route::from_url(&url).map(Msg::ChangePage)
+}
+
+#[wasm_bindgen(start)]
+pub fn render() {
+    seed::App::build(
+        |url, _| {
+            let route = Route::from_url(&url).unwrap_or(Route::Counters);
+            Init::new(Model {
+                page: Page::new(route),
+            })
+        },
+        update,
+        view,
+    )
+    .routes(routes)
+    .build_and_start();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn route(path: &str) -> Option<Route> {
+        Route::from_url(&seed::Url::new(path.split('/').filter(|p| !p.is_empty()).collect()))
+    }
+
+    #[test]
+    fn routes_urls() {
+        assert_eq!(route("/"), Some(Route::Counters));
+        assert_eq!(route("/clock"), Some(Route::Clock));
+        assert_eq!(route("/clock/extra"), Some(Route::Clock));
+        assert_eq!(route("/nowhere"), None);
+        for &expected in &[Route::Counters, Route::Clock] {
+            assert_eq!(route(expected.to_url()), Some(expected));
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! A page with a stopwatch, whose ticks come from a timer outside Seed.
+
+use seed::prelude::*;
+use wasm_bindgen::{closure::Closure, JsCast};
+
+use crate::components;
+
+#[derive(Default)]
+pub struct Model {
+    seconds: i32,
+    ticking: Option<Ticking>,
+}
+
+/// A running timer, and the callback it calls. Dropping it stops the timer; eg when the
+/// reader leaves the page, and its model's dropped.
+struct Ticking {
+    handle: i32,
+    _callback: Closure<dyn FnMut()>,
+}
+
+impl Drop for Ticking {
+    fn drop(&mut self) {
+        seed::window().clear_interval_with_handle(self.handle);
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Msg {
+    Start,
+    Stop,
+    Tick,
+    Adjust(i32),
+}
+
+// ANCHOR: update
+pub fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
+    match msg {
+        Msg::Start => {
+            if model.ticking.is_some() {
+                return;
+            }
+            // The timer's callback isn't part of a view, so nothing maps its messages for us.
+            // `msg_mapper` turns this page's messages into the app's, the way `proxy` did for
+            // `orders`, and the app's `update` takes them from there.
+            let (app, msg_mapper) = (orders.clone_app(), orders.msg_mapper());
+            let callback = Closure::wrap(
+                Box::new(move || app.update(msg_mapper(Msg::Tick))) as Box<dyn FnMut()>
+            );
+            let handle = seed::window()
+                .set_interval_with_callback_and_timeout_and_arguments_0(
+                    callback.as_ref().unchecked_ref(),
+                    1000,
+                )
+                .expect("Problem setting interval");
+
+            model.ticking = Some(Ticking {
+                handle,
+                _callback: callback,
+            });
+        }
+        Msg::Stop => model.ticking = None,
+        Msg::Tick => model.seconds += 1,
+        Msg::Adjust(seconds) => model.seconds += seconds,
+    }
+}
+// ANCHOR_END: update
+
+pub fn view(model: &Model) -> Node<Msg> {
+    div![
+        components::counter("Seconds", model.seconds, Msg::Adjust(-1), Msg::Adjust(1)),
+        if model.ticking.is_some() {
+            button!["Stop", simple_ev(Ev::Click, Msg::Stop)]
+        } else {
+            button!["Start", simple_ev(Ev::Click, Msg::Start)]
+        }
+    ]
+}
@@ -0,0 +1,63 @@
+//! A page of counters, which the reader can add to.
+
+use seed::prelude::*;
+
+use crate::components;
+
+// ANCHOR: counters
+pub struct Model {
+    counts: Vec<i32>,
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        Self { counts: vec![0] }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Msg {
+    Increment(usize),
+    Decrement(usize),
+    AddCounter,
+}
+
+/// Counting needn't send messages or skip rendering, so unlike the clock's `update`, this
+/// takes no orders.
+pub fn update(msg: Msg, model: &mut Model) {
+    match msg {
+        Msg::Increment(i) => model.counts[i] += 1,
+        Msg::Decrement(i) => model.counts[i] -= 1,
+        Msg::AddCounter => model.counts.push(0),
+    }
+}
+
+pub fn view(model: &Model) -> Node<Msg> {
+    div![
+        model.counts.iter().enumerate().map(|(i, count)| {
+            components::counter(
+                &format!("Counter {}", i + 1),
+                *count,
+                Msg::Decrement(i),
+                Msg::Increment(i),
+            )
+        }),
+        button!["Add a counter", simple_ev(Ev::Click, Msg::AddCounter)]
+    ]
+}
+// ANCHOR_END: counters
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn changes_counts() {
+        let mut model = Model::default();
+        update(Msg::AddCounter, &mut model);
+        update(Msg::Increment(1), &mut model);
+        update(Msg::Increment(1), &mut model);
+        update(Msg::Decrement(0), &mut model);
+        assert_eq!(model.counts, vec![-1, 2]);
+    }
+}
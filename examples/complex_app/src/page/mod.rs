@@ -0,0 +1,4 @@
+//! A module per page, each with its own `Model`, `Msg`, `update` and `view`.
+
+pub mod clock;
+pub mod counters;
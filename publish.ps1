@@ -1,6 +1,6 @@
-python setup_markdown.py
-cargo make build_release
-rm pkg/.gitignore
+cargo make prerender
+rm dist/pkg/.gitignore
 git add .
+git add --force dist
 git commit -am "update"
 git push
@@ -0,0 +1,177 @@
+//! Writes every page of the site, prerendered, into `dist`, with the files they load: the
+//! wasm package, styles, images, and each guide section's HTML; a `404.html`; and the sitemap,
+//! `robots.txt` and releases feed. Last, it writes the service worker, `sw.js`, from
+//! `sw.template.js`, to precache all of that for reading offline. Build the wasm first;
+//! `cargo make prerender` does both.
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    fs,
+    hash::Hasher,
+    io,
+    path::{Path, PathBuf},
+};
+
+use seed_homepage::prerender::RenderedPage;
+
+const DIST: &str = "dist";
+
+/// Served alongside the pages, from the same paths.
+const ASSETS: &[&str] = &["style.css", "syntax_style.css", "_redirects", "public", "pkg"];
+
+/// Written into `dist` but not precached: crawlers' and feed readers' files, the page for
+/// missing files, and the service worker itself.
+const NOT_PRECACHED: &[&str] = &[
+    "_redirects",
+    "404.html",
+    "sitemap.xml",
+    "robots.txt",
+    "feed.xml",
+    "sw.js",
+];
+
+/// The app's mount element in `index.html`, which the pages are rendered into.
+const MOUNT: &str = r#"<section id="app"></section>"#;
+
+fn main() -> io::Result<()> {
+    let template = fs::read_to_string("index.html")?;
+    assert!(
+        template.contains(MOUNT),
+        "index.html has no `{}` to render into",
+        MOUNT
+    );
+
+    let dist = Path::new(DIST);
+    if dist.exists() {
+        fs::remove_dir_all(dist)?;
+    }
+    fs::create_dir_all(dist)?;
+    for asset in ASSETS {
+        copy(Path::new(asset), &dist.join(asset))?;
+    }
+
+    let head = seed_homepage::prerender::default_head();
+    assert!(
+        template.contains(&head),
+        "index.html's title and meta tags aren't laid out as `src/meta.rs` writes them:\n{}",
+        head
+    );
+
+    let fill = |page: &RenderedPage| {
+        let mounted = format!(r#"<section id="app">{}</section>"#, page.html);
+        template.replace(MOUNT, &mounted).replace(&head, &page.head)
+    };
+    let pages = seed_homepage::prerender::pages();
+    for page in &pages {
+        let dir = dist.join(&page.path);
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join("index.html"), fill(page))?;
+    }
+    let not_found = seed_homepage::prerender::not_found();
+    fs::write(dist.join(&not_found.path), fill(&not_found))?;
+
+    for (path, html) in seed_homepage::prerender::section_contents() {
+        let path = dist.join(path);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, html)?;
+    }
+
+    fs::write(dist.join("sitemap.xml"), seed_homepage::prerender::sitemap())?;
+    fs::write(dist.join("robots.txt"), seed_homepage::prerender::robots())?;
+    let feed = dist.join(seed_homepage::prerender::FEED_URL.trim_start_matches('/'));
+    fs::write(feed, seed_homepage::prerender::feed())?;
+
+    let precache = precache(dist)?;
+    fs::write(dist.join("sw.js"), service_worker(&precache)?)?;
+
+    println!(
+        "Prerendered {} pages into {}, precaching {} files",
+        pages.len(),
+        DIST,
+        precache.len()
+    );
+    Ok(())
+}
+
+/// The files in `dist` to precache, each with the URL it's served at: pages at their
+/// routes, eg `/guide/view`, and everything else at its path. Of the wasm package, only
+/// the files the page loads are.
+fn precache(dist: &Path) -> io::Result<Vec<(String, PathBuf)>> {
+    let mut files = Vec::new();
+    list_files(dist, &mut files)?;
+
+    let mut precache: Vec<(String, PathBuf)> = files
+        .into_iter()
+        .filter_map(|file| {
+            let path = file.strip_prefix(dist).ok()?.to_str()?.replace('\\', "/");
+            if NOT_PRECACHED.contains(&path.as_str()) {
+                return None;
+            }
+            if path.starts_with("pkg/") && !(path.ends_with(".js") || path.ends_with(".wasm")) {
+                return None;
+            }
+            let url = if path == "index.html" {
+                "/".to_string()
+            } else if path.ends_with("/index.html") {
+                format!("/{}", path.trim_end_matches("/index.html"))
+            } else {
+                format!("/{}", path)
+            };
+            Some((url, file))
+        })
+        .collect();
+    precache.sort();
+    Ok(precache)
+}
+
+/// `sw.template.js`, with its version, a hash of the precached files, and their URLs filled in.
+fn service_worker(precache: &[(String, PathBuf)]) -> io::Result<String> {
+    let template = fs::read_to_string("sw.template.js")?;
+    assert!(
+        template.contains("__VERSION__") && template.contains("__PRECACHE__"),
+        "sw.template.js has no `__VERSION__` and `__PRECACHE__` to fill in"
+    );
+
+    let mut hasher = DefaultHasher::new();
+    for (url, file) in precache {
+        hasher.write(url.as_bytes());
+        hasher.write(&fs::read(file)?);
+    }
+    let urls: Vec<String> = precache
+        .iter()
+        .map(|(url, _)| format!("\n    \"{}\"", url))
+        .collect();
+
+    Ok(template
+        .replace("__VERSION__", &format!("{:016x}", hasher.finish()))
+        .replace("__PRECACHE__", &format!("[{}\n]", urls.join(","))))
+}
+
+/// Every file in a directory, and its subdirectories.
+fn list_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            list_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Copy a file, or a directory and everything in it.
+fn copy(from: &Path, to: &Path) -> io::Result<()> {
+    if from.is_dir() {
+        fs::create_dir_all(to)?;
+        for entry in fs::read_dir(from)? {
+            let entry = entry?;
+            copy(&entry.path(), &to.join(entry.file_name()))?;
+        }
+    } else {
+        fs::copy(from, to)?;
+    }
+    Ok(())
+}
@@ -1,8 +0,0 @@
-pub fn text() -> String {
-r#####"
-<h1 id="writing-complex-apps">Writing complex apps</h1>
-<p>In order to build complex apps, you may need to make use of some advanced features not emphasized so far. Standby for this section to be filled out. Reference <a href="https://github.com/MartinKavik/seed-rs-realworld">MartinKavik’s seed-rs-realworld example</a> for info on when you might need these, and how they work.</p>
-<h2 id="reusable-components">Reusable components</h2>
-<h2 id="mapping-one-type-of-message-to-another">Mapping one type of message to another</h2>
-"#####.into()
-}
\ No newline at end of file
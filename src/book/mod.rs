@@ -1,16 +1,93 @@
-//! Dummy file reuqired by Rust's module system
-//! Markdown-to-HTML provided by https://dillinger.io/
-
-pub mod about;
-pub mod complex_apps;
-pub mod events;
-pub mod fetch;
-pub mod misc;
-pub mod prereqs;
-pub mod quickstart;
-pub mod release_and_debugging;
-pub mod routing;
-pub mod server_integration;
-pub mod structure;
-pub mod view;
-pub mod js;
+//! The guide's content, generated by `build.rs` from the `markdown` directory. There's a
+//! module per version of the guide, eg `v0_4` for `markdown/0.4`, with a module per section
+//! whose `text` function returns its HTML. `VERSIONS` lists them, with their sections in
+//! the order declared in each version's `guide.toml`.
+//!
+//! The HTML is left out of the wasm build, to keep it small; the app fetches each section
+//! from `content_url`, where the prerender binary writes it.
+
+/// A version of the guide, eg `0.4`.
+pub struct Version {
+    pub name: &'static str,
+    pub sections: &'static [Section],
+}
+
+impl Version {
+    /// The section with this slug, or the first section if this version hasn't got it; eg
+    /// to stay on the same section when switching versions.
+    pub fn section_or_first(&self, slug: &str) -> &Section {
+        self.sections.iter().find(|s| s.slug == slug).unwrap_or(&self.sections[0])
+    }
+}
+
+/// A guide section, as declared in its version's `guide.toml`.
+pub struct Section {
+    pub title: &'static str,
+    pub slug: &'static str,
+    pub group: &'static str,
+    #[cfg(not(target_arch = "wasm32"))]
+    pub text: fn() -> String,
+    /// The section's first paragraph, without markup; for its page's meta description.
+    pub description: &'static str,
+    /// The section's prose without markup or code blocks, for search snippets.
+    pub plain_text: &'static str,
+    /// Every heading in the section, in order.
+    pub headings: &'static [Heading],
+}
+
+/// A heading in a guide section; `id` is its anchor, eg `/guide/routing#the-basics`.
+#[derive(Debug)]
+pub struct Heading {
+    pub level: u32,
+    pub id: &'static str,
+    pub title: &'static str,
+}
+
+include!(concat!(env!("OUT_DIR"), "/book.rs"));
+
+/// The newest version of the guide, shown at un-prefixed paths like `/guide/view`.
+pub fn latest() -> &'static Version {
+    &VERSIONS[0]
+}
+
+/// A version of the guide's index in `VERSIONS`, by name.
+pub fn version(name: &str) -> Option<usize> {
+    VERSIONS.iter().position(|v| v.name == name)
+}
+
+/// Where a section's HTML is served, eg `/content/0.4/view.html`.
+pub fn content_url(version: &str, slug: &str) -> String {
+    format!("/content/{}/{}.html", version, slug)
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    /// An older version, without every section the newest has.
+    static OLDER: Version = Version {
+        name: "0.1",
+        sections: &[section("about"), section("quickstart")],
+    };
+
+    const fn section(slug: &'static str) -> Section {
+        Section {
+            title: slug,
+            slug,
+            group: "Guide",
+            text: String::new,
+            description: "",
+            plain_text: "",
+            headings: &[],
+        }
+    }
+
+    #[test]
+    fn switching_versions_keeps_the_section() {
+        assert_eq!(OLDER.section_or_first("quickstart").slug, "quickstart");
+        assert_eq!(OLDER.section_or_first("routing").slug, "about");
+        for section in latest().sections {
+            assert_eq!(latest().section_or_first(section.slug).slug, section.slug);
+        }
+    }
+}
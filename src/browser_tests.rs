@@ -0,0 +1,234 @@
+//! Tests that need a browser, run with `wasm-pack test`; eg `cargo make test_h firefox`.
+//! The rest of the tests live beside the code they cover, and run with `cargo test`.
+//!
+//! `update` needs orders, which only a running app has, so its tests send messages to an app
+//! mounted on an element of their own, and check the model it's left with. The view's tests
+//! build a model, and look through the nodes `view` returns.
+
+use std::cell::Ref;
+
+use seed::{dom_types::El, prelude::*};
+use wasm_bindgen_test::*;
+
+use crate::{
+    book, changelog,
+    components::{guide, header},
+    route::Route,
+    update, view, Model, Msg, Page,
+};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Route a URL, eg `/0.4/guide/view#events`.
+fn route(url: &str) -> Msg {
+    let mut parts = url.splitn(2, '#');
+    let path = parts.next().unwrap();
+    let mut url = seed::Url::new(path.split('/').filter(|p| !p.is_empty()).collect());
+    url.hash = parts.next().map(String::from);
+    crate::routes(url).expect("Every URL should route somewhere")
+}
+
+/// An app on an element of its own, without routing or window events, so it's only changed
+/// by the messages a test sends it.
+fn app() -> seed::App<Msg, Model, Node<Msg>> {
+    let document = seed::document();
+    let mount = document.create_element("div").unwrap();
+    document.body().unwrap().append_child(&mount).unwrap();
+
+    seed::App::build(|_, _| Init::new(Model::default()), update, view)
+        .mount(mount)
+        .build_and_start()
+}
+
+/// The app's model, as its last update left it.
+fn model(app: &seed::App<Msg, Model, Node<Msg>>) -> Ref<Model> {
+    Ref::map(app.data.model.borrow(), |model| {
+        model.as_ref().expect("The app should have a model once it's started")
+    })
+}
+
+/// Every element in a node, depth first.
+fn elements<'a>(node: &'a Node<Msg>, result: &mut Vec<&'a El<Msg>>) {
+    if let Node::Element(el) = node {
+        result.push(el);
+        for child in &el.children {
+            elements(child, result);
+        }
+    }
+}
+
+/// A node's text, without its markup.
+fn text(node: &Node<Msg>) -> String {
+    match node {
+        Node::Element(el) => el_text(el),
+        Node::Text(text) => text.text.to_string(),
+        Node::Empty => String::new(),
+    }
+}
+
+fn el_text(el: &El<Msg>) -> String {
+    el.children.iter().map(text).collect()
+}
+
+fn has_class(el: &El<Msg>, class: &str) -> bool {
+    match el.attrs.vals.get(&At::Class) {
+        Some(AtValue::Some(value)) => value.split_whitespace().any(|c| c == class),
+        _ => false,
+    }
+}
+
+#[wasm_bindgen_test]
+fn routes_each_kind_of_page() {
+    // Parsing URLs into routes is tested in `route`; this checks the message for each.
+    let newest = book::latest().name;
+    for url in &["/", "/guide"] {
+        match route(url) {
+            Msg::ChangeGuideVersion(None) => (),
+            msg => panic!("{} routed to {:?}", url, msg),
+        }
+    }
+    match route(&format!("/{}/guide", newest)) {
+        Msg::ChangeGuideVersion(Some(version)) => assert_eq!(version, newest),
+        msg => panic!("/{}/guide routed to {:?}", newest, msg),
+    }
+    match route("/guide/view#events") {
+        Msg::ChangeGuidePage {
+            version: None,
+            slug,
+            heading: Some(heading),
+        } => assert_eq!((slug.as_str(), heading.as_str()), ("view", "events")),
+        msg => panic!("/guide/view#events routed to {:?}", msg),
+    }
+    match route("/guide/view") {
+        Msg::ChangeGuidePage {
+            version: None,
+            slug,
+            heading: None,
+        } => assert_eq!(slug, "view"),
+        msg => panic!("/guide/view routed to {:?}", msg),
+    }
+    match route(&format!("/{}/guide/view", newest)) {
+        Msg::ChangeGuidePage {
+            version: Some(version),
+            slug,
+            heading: None,
+        } => assert_eq!((version.as_str(), slug.as_str()), (newest, "view")),
+        msg => panic!("/{}/guide/view routed to {:?}", newest, msg),
+    }
+    match route("/changelog") {
+        Msg::ChangePage(Page::Changelog) => (),
+        msg => panic!("/changelog routed to {:?}", msg),
+    }
+    let release = changelog::RELEASES[0].version;
+    match route(&format!("/changelog/{}", release)) {
+        Msg::ChangeRelease(version) => assert_eq!(version, release),
+        msg => panic!("/changelog/{} routed to {:?}", release, msg),
+    }
+    match route("/does/not-exist") {
+        Msg::ChangePage(Page::NotFound(path)) => assert_eq!(path, "does/not-exist"),
+        msg => panic!("/does/not-exist routed to {:?}", msg),
+    }
+}
+
+#[wasm_bindgen_test]
+fn changes_page() {
+    let app = app();
+
+    app.update(Msg::ChangePage(Page::Changelog));
+    assert_eq!(model(&app).page, Page::Changelog);
+    assert_eq!(seed::document().title(), "Changelog - Seed");
+
+    app.update(Msg::ChangePage(Page::NotFound("nothing".into())));
+    assert_eq!(model(&app).page, Page::NotFound("nothing".into()));
+    assert_eq!(seed::document().title(), "Page not found - Seed");
+
+    app.update(Msg::ChangePage(Page::Guide));
+    assert_eq!(model(&app).page, Page::Guide);
+    assert_eq!(model(&app).guide_page, "quickstart");
+}
+
+#[wasm_bindgen_test]
+fn changes_guide_page() {
+    let app = app();
+
+    for section in book::latest().sections {
+        // As if following a search result, with the menu open.
+        app.update(Msg::ChangePage(Page::Changelog));
+        app.update(Msg::Header(header::Msg::Search("view".into())));
+        app.update(Msg::Guide(guide::Msg::ToggleMenu));
+        assert!(!model(&app).search_results.is_empty());
+        assert!(model(&app).menu_open);
+
+        app.update(Msg::ChangeGuidePage {
+            version: None,
+            slug: section.slug.into(),
+            heading: None,
+        });
+        let state = model(&app);
+        assert_eq!(state.page, Page::Guide);
+        assert_eq!(state.guide_page, section.slug);
+        assert_eq!(state.search_query, "");
+        assert!(state.search_results.is_empty());
+        assert!(!state.menu_open);
+    }
+
+    // A section that doesn't exist, or a version that doesn't.
+    app.update(Msg::ChangeGuidePage {
+        version: None,
+        slug: "nothing".into(),
+        heading: None,
+    });
+    assert_eq!(model(&app).page, Page::NotFound("guide/nothing".into()));
+
+    app.update(Msg::ChangeGuidePage {
+        version: Some("0.0".into()),
+        slug: "quickstart".into(),
+        heading: None,
+    });
+    assert_eq!(model(&app).page, Page::NotFound("0.0/guide/quickstart".into()));
+}
+
+#[wasm_bindgen_test]
+fn changing_version_clears_the_search() {
+    let app = app();
+    app.update(Msg::Header(header::Msg::Search("view".into())));
+    assert!(!model(&app).search_results.is_empty());
+
+    app.update(Msg::ChangeGuideVersion(Some(book::latest().name.into())));
+    assert_eq!(model(&app).search_query, "");
+    assert!(model(&app).search_results.is_empty());
+}
+
+#[wasm_bindgen_test]
+fn selects_the_current_section_in_the_menu() {
+    let mut model = Model::default();
+
+    for section in book::latest().sections {
+        model.show(Route::guide_section(None, section.slug));
+        let page = view(&model);
+        let mut els = Vec::new();
+        elements(&page, &mut els);
+
+        let selected: Vec<String> = els
+            .iter()
+            .filter(|el| has_class(el, "guide-menu-selected"))
+            .map(|el| el_text(el))
+            .collect();
+        assert_eq!(selected, vec![section.title.to_string()], "on /guide/{}", section.slug);
+    }
+}
+
+#[wasm_bindgen_test]
+fn renders_each_release_in_the_changelog() {
+    let mut model = Model::default();
+    model.show(Route::Changelog);
+    let page = text(&view(&model));
+
+    for release in changelog::RELEASES {
+        assert!(
+            page.contains(&format!("v{}", release.version)),
+            "The changelog hasn't got {}",
+            release.version
+        );
+    }
+}
@@ -0,0 +1,79 @@
+//! Seed's release history, parsed from `markdown/changelog.md` by `build.rs`.
+
+pub struct Release {
+    pub version: &'static str,
+    /// When it was published, eg `2019-11-05`.
+    pub date: Option<&'static str>,
+    pub entries: &'static [Entry],
+}
+
+pub struct Entry {
+    /// HTML
+    pub text: &'static str,
+    pub breaking: bool,
+}
+
+include!(concat!(env!("OUT_DIR"), "/changelog.rs"));
+
+/// Where the releases' Atom feed is served; the prerender binary writes it.
+pub const FEED_URL: &str = "/feed.xml";
+
+/// Format a changelog date for display, eg `2019-11-05` -> `Nov 05, 2019`.
+pub fn format_date(date: &str) -> String {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let parts: Vec<&str> = date.split('-').collect();
+    match parts.as_slice() {
+        [year, month, day] => match month.parse::<usize>() {
+            Ok(m) if (1..=12).contains(&m) => format!("{} {}, {}", MONTHS[m - 1], day, year),
+            _ => date.into(),
+        },
+        _ => date.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_releases() {
+        let newest = &RELEASES[0];
+        assert_eq!((newest.version, newest.date), ("0.4.2", Some("2019-11-05")));
+        assert_eq!(RELEASES.last().unwrap().version, "0.1.0");
+        for release in RELEASES {
+            assert!(!release.version.starts_with('v'), "{} kept its v", release.version);
+            assert!(!release.entries.is_empty(), "{} has no entries", release.version);
+        }
+    }
+
+    #[test]
+    fn flags_and_strips_breaking_markers() {
+        // After the comment at the top, which mentions the marker too.
+        let source = include_str!("../markdown/changelog.md").to_ascii_lowercase();
+        let source = source.split_once("-->").unwrap().1;
+        let entries = || RELEASES.iter().flat_map(|r| r.entries.iter());
+
+        // The changelog marks them as "(Breaking)" and "(breaking)".
+        assert_eq!(
+            entries().filter(|e| e.breaking).count(),
+            source.matches("(breaking)").count()
+        );
+        for entry in entries() {
+            assert!(
+                !entry.text.to_ascii_lowercase().contains("(breaking)"),
+                "{} kept its marker",
+                entry.text
+            );
+        }
+    }
+
+    #[test]
+    fn formats_dates() {
+        assert_eq!(format_date("2019-11-05"), "Nov 05, 2019");
+        assert_eq!(format_date("2019-13-05"), "2019-13-05");
+        assert_eq!(format_date("soon"), "soon");
+    }
+}
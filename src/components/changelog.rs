@@ -0,0 +1,114 @@
+//! Seed's releases, with their changes; optionally one release, or only breaking changes.
+
+use std::collections::HashSet;
+
+use seed::prelude::*;
+
+use super::raw_html;
+use crate::{changelog, route::Route};
+
+pub struct Props<'a> {
+    pub releases: &'a [changelog::Release],
+    /// Show only this version's changes, eg `0.4.2`.
+    pub release: Option<&'a str>,
+    pub breaking_only: bool,
+    /// Versions whose changes are hidden.
+    pub collapsed: &'a HashSet<String>,
+    /// The releases' feed, to subscribe to.
+    pub feed_url: &'a str,
+}
+
+#[derive(Clone, Debug)]
+pub enum Msg {
+    ToggleRelease(String),
+    ToggleBreakingOnly,
+}
+
+pub fn view(props: &Props) -> Node<Msg> {
+    let Props {
+        releases,
+        release,
+        breaking_only,
+        collapsed,
+        feed_url,
+    } = *props;
+
+    let releases = releases
+        .iter()
+        .filter(|r| release.is_none_or(|version| version == r.version))
+        .map(|r| {
+            let entries: Vec<&changelog::Entry> = r
+                .entries
+                .iter()
+                .filter(|e| e.breaking || !breaking_only)
+                .collect();
+            if entries.is_empty() {
+                return empty![];
+            }
+            let is_collapsed = collapsed.contains(r.version);
+
+            div![
+                class!["release"],
+                h2![
+                    span![
+                        class!["release-toggle"],
+                        if is_collapsed { "▸" } else { "▾" },
+                        simple_ev(Ev::Click, Msg::ToggleRelease(r.version.into()))
+                    ],
+                    a![
+                        format!("v{}", r.version),
+                        attrs! {At::Href => Route::ChangelogRelease(r.version.into()).to_url()}
+                    ],
+                    match r.date {
+                        Some(date) => span![class!["release-date"], changelog::format_date(date)],
+                        None => empty![],
+                    }
+                ],
+                if is_collapsed {
+                    empty![]
+                } else {
+                    ul![entries.iter().map(|e| li![
+                        if e.breaking {
+                            span![class!["breaking"], "Breaking"]
+                        } else {
+                            empty![]
+                        },
+                        raw_html(e.text)
+                    ])]
+                }
+            ]
+        });
+
+    div![
+        class!["guide"],
+        style! {
+            "display" => "grid";
+            "grid-template-columns" => "1fr 2fr 1fr";
+
+            "padding" => unit!(50, px);
+            "color" => "var(--text)";
+        },
+        div![
+            style! {"grid-column" => "2 / 3"},
+            div![
+                class!["changelog-controls"],
+                match release {
+                    Some(_) => a!["All releases", attrs! {At::Href => Route::Changelog.to_url()}],
+                    None => empty![],
+                },
+                a!["Subscribe", attrs! {At::Href => feed_url}],
+                label![
+                    input![
+                        attrs! {
+                            At::Type => "checkbox";
+                            At::Checked => breaking_only.as_at_value();
+                        },
+                        simple_ev(Ev::Click, Msg::ToggleBreakingOnly)
+                    ],
+                    "Breaking changes only"
+                ]
+            ],
+            releases
+        ]
+    ]
+}
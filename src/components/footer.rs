@@ -0,0 +1,10 @@
+//! The copyright notice along the bottom.
+
+use seed::prelude::*;
+
+pub fn view<Ms: Clone + 'static>() -> Node<Ms> {
+    footer![
+        style! {"display" => "flex"; "justify-content" => "center"},
+        h4!["© 2019 David O'Connor"]
+    ]
+}
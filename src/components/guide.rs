@@ -0,0 +1,260 @@
+//! A guide section, with the menu of sections beside it, or in a drawer above it on narrow
+//! screens, and its table of contents.
+
+use seed::prelude::*;
+
+use super::{not_found, raw_html};
+use crate::{book, demos, route::Route};
+
+#[derive(Clone, Debug)]
+pub struct GuideSection {
+    pub title: String,
+    pub path: String,  // For use with routing.
+    pub group: String,  // Sections are listed under their group's heading in the menu.
+    pub headings: &'static [book::Heading],  // For the table of contents.
+}
+
+/// A guide section's HTML, which is fetched when the section's first opened.
+#[derive(Clone, Debug)]
+pub enum Content {
+    Loading,
+    Loaded(String),
+    Failed(String),  // Why, for display.
+}
+
+pub struct Props<'a> {
+    /// The guide version; `None` is the newest.
+    pub version: Option<&'a str>,
+    pub sections: &'a [GuideSection],
+    /// The section's path; the not-found page if it isn't one of `sections`.
+    pub guide_page: &'a str,
+    /// The section's HTML, if it's been fetched.
+    pub content: Option<&'a Content>,
+    /// Where it's fetched from.
+    pub content_url: &'a str,
+    pub demos: &'a demos::Model,
+    pub narrow: bool,
+    /// Whether the menu's drawer is open, on narrow screens.
+    pub menu_open: bool,
+}
+
+/// Marks the section's HTML with where it's fetched from, so the app can reuse a
+/// prerendered page's rather than fetching it again.
+pub const CONTENT_URL_ATTR: &str = "data-content-url";
+
+#[derive(Clone, Debug)]
+pub enum Msg {
+    ToggleMenu,
+    Click(web_sys::Event),  // A click inside the section's HTML, eg on a copy button.
+    Retry,  // Fetch the section again, after it failed.
+    Demo(demos::Msg),  // From a live demo in the section.
+}
+
+pub fn view(props: &Props) -> Node<Msg> {
+    let Props {
+        version,
+        sections,
+        guide_page,
+        content,
+        content_url,
+        demos,
+        narrow,
+        menu_open,
+    } = *props;
+
+    let menu_item_style = style! {
+        "display" => "flex";  // So we can vertically center
+        "align-items" => "center";
+        "padding" => unit!(4, px);
+        "cursor" => "pointer";
+        "height" => unit!(40, px);
+        "margin-bottom" => 0;
+        "width" => unit!(100, %);
+        "color" => "var(--text)";
+        "font-size" => unit!(1.2, em);
+    };
+
+    let mut menu_items = Vec::new();
+    for (i, s) in sections.iter().enumerate() {
+        // Start a new group heading whenever the group changes.
+        if i == 0 || sections[i - 1].group != s.group {
+            menu_items.push(h5![class!["guide-menu-group"], s.group]);
+        }
+        menu_items.push(h4![
+            &menu_item_style,
+            attrs! {
+                At::Class => if s.path == guide_page {"guide-menu-selected"} else {"guide-menu"};
+                At::Href => Route::guide_section(version, &s.path).to_url()
+            },
+            s.title
+        ]);
+    }
+
+    let section = sections.iter().find(|s| s.path == guide_page);
+
+    // On narrow screens, the menu's a drawer above the section, and there's no table of
+    // contents.
+    let menu = if narrow {
+        let current = section.map_or("Sections", |s| s.title.as_str());
+        vec![
+            button![
+                class!["guide-menu-toggle"],
+                attrs! {At::Custom("aria-expanded".into()) => menu_open.to_string()},
+                format!("☰ {}", current),
+                simple_ev(Ev::Click, Msg::ToggleMenu)
+            ],
+            if menu_open {
+                div![class!["guide-menu-drawer"], menu_items]
+            } else {
+                empty![]
+            },
+        ]
+    } else {
+        menu_items
+    };
+
+    div![
+        style! {
+            "display" => "grid";
+            "grid-template-columns" => if narrow {"100%"} else {"200px auto 220px"};
+            "color" => "var(--text)";
+            "grid-auto-rows" => if narrow {"auto"} else {"1fr"};
+            "align-items" => "start";
+        },
+        div![
+            style! {"display" => "flex"; "flex-direction" => "column";
+            "grid-column" => "1 / 2";
+             "justify-content" => "flex-start";
+            "padding" => unit!(10, px);},
+            menu
+        ],
+        div![
+            class!["guide"],
+            style! {
+                "display" => "flex";
+                "flex-direction" => "column";
+                "grid-column" => if narrow {"1 / 2"} else {"2 / 3"};
+                "padding" => if narrow {unit!(20, px)} else {unit!(80, px)};
+            },
+            raw_ev(Ev::Click, Msg::Click),
+            match (section, content) {
+                (None, _) => vec![not_found::view(&not_found::Props {
+                    version,
+                    sections,
+                    path: guide_page,
+                })],
+                (Some(_), Some(Content::Loaded(html))) => vec![div![
+                    class!["guide-section"],
+                    attrs! {At::Custom(CONTENT_URL_ATTR.into()) => content_url},
+                    section_html(html, demos)
+                ]],
+                (Some(_), Some(Content::Failed(reason))) => vec![div![
+                    class!["guide-error"],
+                    p!["This section couldn't be loaded."],
+                    p![class!["guide-error-reason"], reason],
+                    button!["Try again", simple_ev(Ev::Click, Msg::Retry)]
+                ]],
+                (Some(_), Some(Content::Loading)) | (Some(_), None) => {
+                    vec![p![class!["guide-loading"], "Loading…"]]
+                }
+            },
+            match section {
+                Some(_) => pager(version, sections, guide_page),
+                None => empty![],
+            }
+        ],
+        match section {
+            Some(section) if !narrow => table_of_contents(version, section),
+            _ => empty![],
+        }
+    ]
+}
+
+/// The sections before and after `guide_page`, in reading order.
+pub fn neighbours<'a>(
+    sections: &'a [GuideSection],
+    guide_page: &str,
+) -> (Option<&'a GuideSection>, Option<&'a GuideSection>) {
+    match sections.iter().position(|s| s.path == guide_page) {
+        Some(i) => (
+            i.checked_sub(1).map(|prev| &sections[prev]),
+            sections.get(i + 1),
+        ),
+        None => (None, None),
+    }
+}
+
+/// A section's HTML, with its live demos in place.
+fn section_html(html: &str, demos_model: &demos::Model) -> Vec<Node<Msg>> {
+    let mut nodes = Vec::new();
+    for part in demos::split(html) {
+        match part {
+            demos::Part::Html(html) => nodes.extend(raw_html(html)),
+            demos::Part::Demo(name) => {
+                if let Some(demo) = demos::view(name, demos_model) {
+                    nodes.push(demo.map_message(Msg::Demo));
+                }
+            }
+        }
+    }
+    nodes
+}
+
+/// Links to the previous and next sections, at the bottom of each one.
+fn pager(version: Option<&str>, sections: &[GuideSection], guide_page: &str) -> Node<Msg> {
+    let (prev, next) = neighbours(sections, guide_page);
+    let link = |section: &GuideSection, label: String, class: &str, shortcut: &str| {
+        a![
+            class![class],
+            attrs! {
+                At::Href => Route::guide_section(version, &section.path).to_url();
+                At::Title => format!("Keyboard: {}", shortcut);
+            },
+            label
+        ]
+    };
+
+    div![
+        class!["guide-pager"],
+        match prev {
+            Some(s) => link(s, format!("← {}", s.title), "guide-prev", "← or k"),
+            None => empty![],
+        },
+        match next {
+            Some(s) => link(s, format!("{} →", s.title), "guide-next", "→ or j"),
+            None => empty![],
+        }
+    ]
+}
+
+/// Links to the section's headings, kept in view while scrolling.
+fn table_of_contents(version: Option<&str>, section: &GuideSection) -> Node<Msg> {
+    // The level-1 heading is the section's title; skip it, and anything too deep to be useful.
+    let items = section
+        .headings
+        .iter()
+        .filter(|h| h.level == 2 || h.level == 3)
+        .map(|h| {
+            let route = Route::GuideSection {
+                version: version.map(String::from),
+                slug: section.path.clone(),
+                fragment: Some(h.id.into()),
+            };
+            li![
+                style! {"margin-left" => unit!((h.level - 2) * 16, px)},
+                a![h.title, attrs! {At::Href => route.to_url()}]
+            ]
+        });
+
+    nav![
+        class!["guide-toc"],
+        style! {
+            "grid-column" => "3 / 4";
+            "position" => "sticky";
+            "top" => 0;
+            "padding" => unit!(10, px);
+        },
+        h5![class!["guide-menu-group"], "On this page"],
+        ul![items]
+    ]
+}
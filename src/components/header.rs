@@ -0,0 +1,96 @@
+//! The links, search box, guide version picker and theme toggle along the top.
+
+use seed::prelude::*;
+
+use crate::{book, route::Route, theme::Theme};
+
+pub struct Props<'a> {
+    /// Seed's latest release, for the API docs link.
+    pub version: &'a str,
+    pub search_query: &'a str,
+    pub guide_versions: &'a [book::Version],
+    /// Index into `guide_versions` of the one that's showing.
+    pub guide_version: usize,
+    pub theme: Theme,
+}
+
+#[derive(Clone, Debug)]
+pub enum Msg {
+    Search(String),
+    SelectGuideVersion(String),  // Keeps the current section, if the version has it.
+    ChangeTheme(Theme),
+}
+
+pub fn view(props: &Props) -> Node<Msg> {
+    let Props {
+        version,
+        search_query,
+        guide_versions,
+        guide_version,
+        theme,
+    } = *props;
+
+    let link_style = style! {
+        "margin-left" => unit!(20, px);
+        "margin-right" => unit!(20, px);
+        "font-weight" => "bold";
+        "font-size" => unit!(1.2, em);
+        "color" => "var(--text)";
+        "cursor" => "pointer";
+    };
+
+    header![
+        style! {"display" => "flex"; "justify-content" => "flex-end"; "align-items" => "center"},
+        input![
+            attrs! {
+                At::Type => "search";
+                At::Placeholder => "Search the guide";
+                At::Value => search_query;
+            },
+            input_ev(Ev::Input, Msg::Search),
+        ],
+        select![
+            attrs! {At::Title => "Guide version"},
+            guide_versions.iter().enumerate().map(|(i, v)| {
+                option![
+                    attrs! {
+                        At::Value => v.name;
+                        At::Selected => (i == guide_version).as_at_value();
+                    },
+                    format!("Guide: v{}", v.name)
+                ]
+            }),
+            input_ev(Ev::Change, Msg::SelectGuideVersion),
+        ],
+        button![
+            class!["theme-toggle"],
+            attrs! {At::Title => format!("Switch to the {} theme", theme.next().label().to_lowercase())},
+            format!("Theme: {}", theme.label()),
+            simple_ev(Ev::Click, Msg::ChangeTheme(theme.next()))
+        ],
+        ul![
+            a![&link_style, "Guide", attrs! {At::Href => Route::Guide { version: None }.to_url()}],
+            a![&link_style, "Changelog", attrs! {At::Href => Route::Changelog.to_url()}],
+            a![
+                &link_style,
+                "Repo",
+                attrs! {At::Href => "https://github.com/David-OConnor/seed"}
+            ],
+            a![
+                &link_style,
+                "Quickstart repo",
+                attrs! {At::Href => "https://github.com/David-OConnor/seed-quickstart"}
+            ],
+            a![
+                &link_style,
+                "Crate",
+                attrs! {At::Href => "https://crates.io/crates/seed"}
+            ],
+            a![
+                &link_style,
+                "API docs",
+                attrs! {At::Href => format!("https://docs.rs/seed/{}", version)}
+            ]
+        ]
+    ]
+}
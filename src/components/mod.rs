@@ -0,0 +1,27 @@
+//! The homepage's views, as components: the pattern the guide's Complex apps section
+//! describes. Each takes a `Props` struct with everything it shows, rather than the whole
+//! `Model`. Those with events send their own `Msg`, which `view` maps into the top-level
+//! `Msg` with `MessageMapper`; the rest are generic over the message type, so any app can
+//! use them.
+
+pub mod changelog;
+pub mod footer;
+pub mod guide;
+pub mod header;
+pub mod not_found;
+pub mod search_results;
+pub mod title;
+
+use seed::prelude::*;
+
+/// Markup that's already HTML, like a guide section. `raw!` parses it with the browser's
+/// DOM, which doesn't exist when prerendering, so there it's passed through as-is.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn raw_html<Ms: Clone + 'static>(html: &str) -> Vec<Node<Ms>> {
+    raw![html]
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn raw_html<Ms: Clone + 'static>(html: &str) -> Vec<Node<Ms>> {
+    vec![crate::prerender::raw(html)]
+}
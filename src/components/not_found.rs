@@ -0,0 +1,96 @@
+//! The page for paths that don't match anything, suggesting guide sections they might
+//! have meant.
+
+use seed::prelude::*;
+
+use super::guide::GuideSection;
+use crate::route::Route;
+
+pub struct Props<'a> {
+    /// The guide version to suggest sections from; `None` is the newest.
+    pub version: Option<&'a str>,
+    pub sections: &'a [GuideSection],
+    /// The path that didn't match, eg `guide/rooting`.
+    pub path: &'a str,
+}
+
+/// Levenshtein distance between two strings; used to suggest sections for mistyped paths.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    // The previous row of the distance matrix.
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut row = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + if ca == *cb { 0 } else { 1 };
+            row.push(substitution.min(prev[j + 1] + 1).min(row[j] + 1));
+        }
+        prev = row;
+    }
+    prev[b.len()]
+}
+
+pub fn view<Ms: Clone + 'static>(props: &Props) -> Node<Ms> {
+    let Props {
+        version,
+        sections,
+        path,
+    } = *props;
+
+    // Compare against the last part of the path, eg `rooting` for `guide/rooting`.
+    let requested = path.rsplit('/').next().unwrap_or(path).to_lowercase();
+
+    let mut closest: Vec<(usize, &GuideSection)> = sections
+        .iter()
+        .map(|s| (edit_distance(&requested, &s.path), s))
+        .filter(|(distance, s)| {
+            *distance <= s.path.len() / 2 || (!requested.is_empty() && s.path.contains(&requested))
+        })
+        .collect();
+    closest.sort_by_key(|(distance, _)| *distance);
+
+    let suggestions: Vec<Node<Ms>> = closest
+        .iter()
+        .take(3)
+        .map(|(_, s)| {
+            li![a![
+                s.title,
+                attrs! {At::Href => Route::guide_section(version, &s.path).to_url()}
+            ]]
+        })
+        .collect();
+
+    div![
+        class!["guide"],
+        style! {
+            "padding" => unit!(50, px);
+            "color" => "var(--text)";
+        },
+        h1!["Page not found"],
+        p![format!(
+            "There's nothing at {}.",
+            Route::NotFound(path.into()).to_url()
+        )],
+        if suggestions.is_empty() {
+            p!["Try one of the guide sections in the menu."]
+        } else {
+            div![p!["Did you mean:"], ul![suggestions]]
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edit_distances() {
+        assert_eq!(edit_distance("routing", "routing"), 0);
+        assert_eq!(edit_distance("rooting", "routing"), 1);
+        assert_eq!(edit_distance("veiw", "view"), 2);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "view"), 4);
+        assert_eq!(edit_distance("events", ""), 6);
+    }
+}
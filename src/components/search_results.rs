@@ -0,0 +1,61 @@
+//! The guide sections matching a search, below the header, as links with snippets.
+
+use seed::prelude::*;
+
+use super::guide::GuideSection;
+use crate::{route::Route, search::SearchResult};
+
+pub struct Props<'a> {
+    /// The guide version searched; `None` is the newest.
+    pub version: Option<&'a str>,
+    pub sections: &'a [GuideSection],
+    pub query: &'a str,
+    pub results: &'a [SearchResult],
+}
+
+pub fn view<Ms: Clone + 'static>(props: &Props) -> Node<Ms> {
+    let Props {
+        version,
+        sections,
+        query,
+        results,
+    } = *props;
+
+    if query.trim().is_empty() {
+        return empty![];
+    }
+
+    let items: Vec<Node<Ms>> = results
+        .iter()
+        .map(|r| {
+            let section = &sections[r.section];
+            let snippet = r.snippet.iter().map(|(text, highlighted)| {
+                if *highlighted {
+                    span![class!["search-highlight"], text.as_str()]
+                } else {
+                    span![text.as_str()]
+                }
+            });
+
+            div![
+                class!["search-result"],
+                attrs! {At::Href => Route::guide_section(version, &section.path).to_url()},
+                h4![section.title.as_str()],
+                p![snippet],
+            ]
+        })
+        .collect();
+
+    div![
+        class!["search-results"],
+        style! {
+            "padding" => "10px 50px";
+            "color" => "var(--text)";
+        },
+        if items.is_empty() {
+            vec![p![format!("No results for \"{}\"", query)]]
+        } else {
+            items
+        }
+    ]
+}
@@ -0,0 +1,59 @@
+//! The logo, tagline and latest release, and Seed's main features.
+
+use seed::prelude::*;
+
+use crate::changelog;
+
+pub struct Props<'a> {
+    /// Seed's latest release, and when it was released.
+    pub version: &'a str,
+    pub release_date: Option<&'a str>,
+    pub narrow: bool,
+}
+
+pub fn view<Ms: Clone + 'static>(props: &Props) -> Node<Ms> {
+    let Props {
+        version,
+        release_date,
+        narrow,
+    } = *props;
+
+    let latest_release = match release_date {
+        Some(date) => format!("Latest release: {} ({})", version, changelog::format_date(date)),
+        None => format!("Latest release: {}", version),
+    };
+
+    // On narrow screens, the features stack instead of sitting side by side.
+    let features = ["Expressive view syntax", "Compile-time error checking", "Clean architecture"];
+    let feature_position = |i: usize| {
+        if narrow {
+            (format!("{} / {}", i + 2, i + 3), "1 / 2".to_string())
+        } else {
+            ("2 / 3".to_string(), format!("{} / {}", i + 1, i + 2))
+        }
+    };
+
+    div![
+        style! {
+        // todo look up areas
+        "display" => "grid";
+        "grid-template-rows" => if narrow {"auto"} else {"auto 160px"};
+        "grid-template-columns" => if narrow {"1fr"} else {"1fr 1fr 1fr"};
+        "text-align" => "center";
+        "align-items" => "center";
+        },
+        div![
+            style! {"grid-row" => "1/2"; "grid-column" => "1 / -1"},
+            img![
+                attrs! {At::Src => "/public/seed_logo.svg"; At::Width => if narrow {160} else {256}; At::Alt => "Seed"},
+                style! {"margin-top" => unit!(30, px)},
+            ],
+            h2!["A Rust framework for creating web apps"],
+            h3![latest_release],
+        ],
+        features.iter().enumerate().map(|(i, feature)| {
+            let (row, column) = feature_position(i);
+            div![style! {"grid-row" => row; "grid-column" => column}, h2![feature]]
+        }),
+    ]
+}
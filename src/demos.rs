@@ -0,0 +1,188 @@
+//! Live demos, embedded in guide sections next to the code they illustrate. A section embeds
+//! one with an HTML comment on a line of its own in its Markdown, eg `<!-- demo: counter -->`,
+//! and `guide` renders the demo in its place. Each demo is a small Seed app of its own, with
+//! a model, `Msg`, `update` and `view`; the guide component wraps its messages in
+//! `guide::Msg::Demo`, and maps its views and window listeners with `MessageMapper`.
+
+use seed::prelude::*;
+use wasm_bindgen::JsCast;
+
+const MARKER_START: &str = "<!-- demo: ";
+const MARKER_END: &str = " -->";
+
+/// Marks a rendered demo with its name.
+const DEMO_ATTR: &str = "data-demo";
+
+/// The demos' state; they keep it while the reader moves between sections.
+#[derive(Default)]
+pub struct Model {
+    count: i32,
+    text: String,
+    watching: bool,  // Whether the window events demo is listening.
+    coords: (i32, i32),  // The mouse's, in the window.
+    last_key: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub enum Msg {
+    Increment,
+    Decrement,
+    ChangeText(String),
+    ClearText,
+    ToggleWatching,
+    UpdateCoords(web_sys::MouseEvent),
+    KeyPressed(web_sys::KeyboardEvent),
+}
+
+pub fn update(msg: Msg, model: &mut Model) {
+    match msg {
+        Msg::Increment => model.count += 1,
+        Msg::Decrement => model.count -= 1,
+        Msg::ChangeText(text) => model.text = text,
+        Msg::ClearText => model.text.clear(),
+        Msg::ToggleWatching => model.watching = !model.watching,
+        Msg::UpdateCoords(ev) => model.coords = (ev.client_x(), ev.client_y()),
+        Msg::KeyPressed(ev) => model.last_key = Some(ev.key()),
+    }
+}
+
+/// A part of a guide section's HTML: markup, or where a demo goes.
+pub enum Part<'a> {
+    Html(&'a str),
+    Demo(&'a str),  // Its name, eg `counter`.
+}
+
+/// Split a section's HTML at its demos' markers.
+pub fn split(html: &str) -> Vec<Part<'_>> {
+    let mut parts = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find(MARKER_START) {
+        let name_start = start + MARKER_START.len();
+        let name_end = match rest[name_start..].find(MARKER_END) {
+            Some(len) => name_start + len,
+            None => break,
+        };
+        parts.push(Part::Html(&rest[..start]));
+        parts.push(Part::Demo(&rest[name_start..name_end]));
+        rest = &rest[name_end + MARKER_END.len()..];
+    }
+    parts.push(Part::Html(rest));
+    parts
+}
+
+/// A demo, by name; `None` if there's no such demo.
+pub fn view(name: &str, model: &Model) -> Option<Node<Msg>> {
+    let demo = match name {
+        "counter" => counter(model.count),
+        "controlled_input" => controlled_input(&model.text),
+        "window_events" => window_events_view(model),
+        _ => return None,
+    };
+    Some(div![
+        class!["demo"],
+        attrs! {At::Custom(DEMO_ATTR.into()) => name},
+        span![class!["demo-label"], "Live demo"],
+        demo
+    ])
+}
+
+/// Put the markers back in a section's rendered HTML, in place of its demos; eg to reuse a
+/// prerendered section's. Changes `section`, so pass a copy of one that's in the page.
+pub fn restore_markers(section: &web_sys::Element) {
+    let document = seed::document();
+    let demos = match section.query_selector_all(&format!("[{}]", DEMO_ATTR)) {
+        Ok(demos) => demos,
+        Err(_) => return,
+    };
+
+    for i in 0..demos.length() {
+        let demo = match demos.item(i).and_then(|d| d.dyn_into::<web_sys::Element>().ok()) {
+            Some(demo) => demo,
+            None => continue,
+        };
+        if let (Some(name), Some(parent)) = (demo.get_attribute(DEMO_ATTR), demo.parent_node()) {
+            // Serialized as `MARKER_START`, the name, and `MARKER_END`.
+            let marker = document.create_comment(&format!(" demo: {} ", name));
+            parent.replace_child(&marker, &demo).ok();
+        }
+    }
+}
+
+fn counter(count: i32) -> Node<Msg> {
+    div![
+        class!["demo-row"],
+        button!["-", simple_ev(Ev::Click, Msg::Decrement)],
+        span![class!["demo-count"], count.to_string()],
+        button!["+", simple_ev(Ev::Click, Msg::Increment)]
+    ]
+}
+
+fn controlled_input(text: &str) -> Node<Msg> {
+    div![
+        div![
+            class!["demo-row"],
+            input![
+                attrs! {At::Value => text; At::Placeholder => "Type something"},
+                input_ev(Ev::Input, Msg::ChangeText)
+            ],
+            button!["Clear", simple_ev(Ev::Click, Msg::ClearText)]
+        ],
+        p![format!("The model's text: {:?}, {} characters", text, text.chars().count())]
+    ]
+}
+
+fn window_events_view(model: &Model) -> Node<Msg> {
+    div![
+        button![
+            if model.watching { "Stop watching" } else { "Watch the window" },
+            simple_ev(Ev::Click, Msg::ToggleWatching)
+        ],
+        if model.watching {
+            div![
+                p![format!("Mouse: ({}, {})", model.coords.0, model.coords.1)],
+                p![format!(
+                    "Last key pressed: {}",
+                    model.last_key.as_ref().map_or("none yet", String::as_str)
+                )]
+            ]
+        } else {
+            empty![]
+        }
+    ]
+}
+
+/// The window events demo's listeners, while it's watching.
+pub fn window_events(model: &Model) -> Vec<seed::events::Listener<Msg>> {
+    let mut result = Vec::new();
+    if model.watching {
+        result.push(mouse_ev(Ev::MouseMove, Msg::UpdateCoords));
+        result.push(keyboard_ev(Ev::KeyDown, Msg::KeyPressed));
+    }
+    result
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::book;
+
+    #[test]
+    fn every_marker_names_a_demo() {
+        for version in book::VERSIONS {
+            for section in version.sections {
+                for part in split(&(section.text)()) {
+                    if let Part::Demo(name) = part {
+                        assert!(
+                            view(name, &Model::default()).is_some(),
+                            "{}/{} embeds `{}`, which isn't a demo",
+                            version.name,
+                            section.slug,
+                            name
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
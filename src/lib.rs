@@ -2,81 +2,216 @@
 //! simple interactions, markdown elements, basic routing, and lots of view markup.
 
 mod book;
+#[cfg(all(test, target_arch = "wasm32"))]
+mod browser_tests;
+mod changelog;
+mod components;
+mod demos;
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod links;
+mod meta;
+mod offline;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod prerender;
+mod route;
+mod search;
+mod theme;
+
+/// The guide's Rust snippets, as doctests; see `build.rs`.
+#[cfg(doctest)]
+mod snippets {
+    include!(concat!(env!("OUT_DIR"), "/snippets.rs"));
+}
 
 #[macro_use]
 extern crate seed;
 use seed::prelude::*;
+use futures::Future;
+use wasm_bindgen_futures::JsFuture;
+use seed::fetch::{Method, Request};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+use wasm_bindgen::{JsCast, JsValue};
+
+use meta::Meta;
+use components::{
+    footer,
+    guide::{self, neighbours, Content, GuideSection},
+    header, not_found, search_results, title,
+};
+use route::Route;
+use theme::Theme;
 
 // Model
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 enum Page {
     Guide,
     Changelog,
+    NotFound(String), // The path that didn't match anything, eg `guide/does-not-exist`.
 }
 
-impl ToString for Page {
-    fn to_string(&self) -> String {
+impl fmt::Display for Page {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         // Eg for url routing
         match self {
-            Page::Guide => "guide".into(),
-            Page::Changelog => "changelog".into(),
+            Page::Guide => f.write_str("guide"),
+            Page::Changelog => f.write_str("changelog"),
+            Page::NotFound(path) => f.write_str(path),
         }
     }
 }
 
-#[derive(Clone, Debug)]
-struct GuideSection {
-    title: String,
-    content: String,
-    path: String,  // For use with routing.
-}
-
-
 struct Model {
     page: Page,
+    guide_version: usize,  // Index into `book::VERSIONS`; 0 is the newest.
     guide_page: String,  // corresponds to the `path` field of GuidePage
-    guide_sections: Vec<GuideSection>,
+    guide_sections: Vec<GuideSection>,  // The sections of `guide_version`.
+    guide_content: HashMap<String, Content>,  // Sections' HTML, by `book::content_url`.
+    scroll_to: Option<String>,  // A heading to scroll to once its section has loaded.
+    toast: Option<(u32, String)>,  // A brief notice, eg "Copied", and its id for hiding it.
+    theme: Theme,
+    window_width: f64,  // In CSS pixels; kept current by a resize listener.
+    menu_open: bool,  // Whether the guide menu's drawer is open, on narrow screens.
+    search_query: String,
+    search_results: Vec<search::SearchResult>,
+    changelog_release: Option<String>,  // Show only this version's changelog, eg `0.4.2`.
+    breaking_only: bool,  // Filter the changelog to breaking changes.
+    collapsed_releases: HashSet<String>,  // Versions whose changelog entries are hidden.
+    update_available: bool,  // A new version of the site is waiting; see `offline`.
+    demos: demos::Model,  // The guide's live demos.
+}
+
+/// Below this window width, in CSS pixels, the layout collapses to one column.
+const NARROW_WIDTH: f64 = 800.;
+
+/// The window's width, in CSS pixels.
+fn window_width() -> Option<f64> {
+    seed::window().inner_width().ok().and_then(|width| width.as_f64())
+}
+
+/// A version's sections, whose order is declared in its `guide.toml`.
+fn guide_sections(version: &book::Version) -> Vec<GuideSection> {
+    version
+        .sections
+        .iter()
+        .map(|s| GuideSection {
+            title: s.title.to_string(),
+            path: s.slug.to_string(),
+            group: s.group.to_string(),
+            headings: s.headings,
+        })
+        .collect()
+}
+
+/// The version to put in guide routes: none for the newest, so its URLs stay short.
+fn route_version(version: usize) -> Option<&'static str> {
+    if version == 0 {
+        None
+    } else {
+        Some(book::VERSIONS[version].name)
+    }
+}
+
+impl Model {
+    /// Switch to a version of the guide, by name; `None` is the newest. Returns `false` if
+    /// there's no such version.
+    fn set_guide_version(&mut self, name: Option<&str>) -> bool {
+        let index = match name {
+            Some(name) => match book::version(name) {
+                Some(i) => i,
+                None => return false,
+            },
+            None => 0,
+        };
+
+        if index != self.guide_version || self.guide_sections.is_empty() {
+            self.guide_version = index;
+            self.guide_sections = guide_sections(&book::VERSIONS[index]);
+        }
+        true
+    }
+
+    /// Where the current guide section's HTML is fetched from; its key in `guide_content`.
+    fn content_url(&self) -> String {
+        book::content_url(book::VERSIONS[self.guide_version].name, &self.guide_page)
+    }
+
+    /// Whether the window's too narrow for the guide's menu and table of contents to fit
+    /// beside it.
+    fn narrow(&self) -> bool {
+        self.window_width < NARROW_WIDTH
+    }
+
+    fn content_loaded(&self) -> bool {
+        matches!(self.guide_content.get(&self.content_url()), Some(Content::Loaded(_)))
+    }
+
+    /// Show a route's page; the not-found page if its version, section or release doesn't
+    /// exist. Shared by routing and prerendering.
+    fn show(&mut self, route: Route) {
+        let not_found = Page::NotFound(route.path().join("/"));
+        self.changelog_release = None;
+
+        match route {
+            Route::Guide { version } => {
+                if self.set_guide_version(version.as_deref()) {
+                    self.page = Page::Guide;
+                    // Stay on the same section, if this version has it.
+                    if !self.guide_sections.iter().any(|s| s.path == self.guide_page) {
+                        self.guide_page = self.guide_sections[0].path.clone();
+                    }
+                } else {
+                    self.page = not_found;
+                }
+            }
+            Route::GuideSection { version, slug, .. } => {
+                if self.set_guide_version(version.as_deref())
+                    && self.guide_sections.iter().any(|s| s.path == slug)
+                {
+                    self.page = Page::Guide;
+                    self.guide_page = slug;
+                } else {
+                    self.page = not_found;
+                }
+            }
+            Route::Changelog => self.page = Page::Changelog,
+            Route::ChangelogRelease(version) => {
+                if changelog::RELEASES.iter().any(|r| r.version == version) {
+                    self.page = Page::Changelog;
+                    self.changelog_release = Some(version);
+                } else {
+                    self.page = not_found;
+                }
+            }
+            Route::NotFound(path) => self.page = Page::NotFound(path),
+        }
+    }
 }
 
 // Setup a default here, for initialization later.
 impl Default for Model {
     fn default() -> Self {
-        let mut guide_sections = Vec::new();
-        let md_texts = vec![
-            ("Quickstart", book::quickstart::text()),
-            ("Prereqs", book::prereqs::text()),
-            ("Structure", book::structure::text()),
-            ("View", book::view::text()),
-            ("Events", book::events::text()),
-            ("Http requests and state", book::fetch::text()),
-            ("Routing", book::routing::text()),
-            ("Misc features", book::misc::text()),
-            ("Javascript interaction", book::js::text()),
-            (
-                "Release and debugging",
-                book::release_and_debugging::text(),
-            ),
-            ("Complex apps", book::complex_apps::text()),
-            (
-                "Server integration",
-                book::server_integration::text(),
-            ),
-            ("About", book::about::text()),
-        ];
-
-        for (title, md_text) in md_texts {
-            guide_sections.push(GuideSection {
-                title: title.to_string(),
-                content: md_text,
-                path: title.to_lowercase().replace(" ", "-"),
-            });
-        }
-
         Self {
             page: Page::Guide,
+            guide_version: 0,
             guide_page: "quickstart".into(),
-            guide_sections,
+            guide_sections: guide_sections(book::latest()),
+            guide_content: HashMap::new(),
+            scroll_to: None,
+            toast: None,
+            theme: Theme::System,
+            window_width: 1280.,  // Before there's a window, eg prerendering, assume a desktop.
+            menu_open: false,
+            search_query: String::new(),
+            search_results: Vec::new(),
+            changelog_release: None,
+            breaking_only: false,
+            collapsed_releases: HashSet::new(),
+            update_available: false,
+            demos: demos::Model::default(),
         }
     }
 }
@@ -84,377 +219,458 @@ impl Default for Model {
 #[derive(Clone, Debug)]
 enum Msg {
     ChangePage(Page),
-    ChangeGuidePage(String),
+    ChangeGuideVersion(Option<String>),  // `None` is the newest version.
+    ChangeGuidePage {
+        version: Option<String>,
+        slug: String,  // The section's path.
+        heading: Option<String>,  // A heading id to scroll to.
+    },
+    ContentRendered,  // The section's HTML is in the DOM.
+    Copied(bool),  // Whether a code block's code was copied to the clipboard.
+    HideToast(u32),
+    Resized,
+    LoadContent,  // Fetch the current section's HTML, unless it's loaded or loading.
+    ContentFetched {
+        url: String,
+        result: Result<String, String>,
+    },
+    ChangeRelease(String),
+    PrevSection,
+    NextSection,
+    KeyDown(web_sys::KeyboardEvent),
+    UpdateAvailable,  // From the service worker.
+    Reload,  // Switch to the new version.
+    // From the components in `view`.
+    Header(header::Msg),
+    Guide(guide::Msg),
+    Changelog(components::changelog::Msg),
 }
 
 /// The sole source of updating the model; returns a fresh one.
-fn update(msg: Msg, model: &mut Model, _orders: &mut impl Orders<Msg>) {
+fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
+    let meta = Meta::of(model);
+
     match msg {
-        Msg::ChangePage(page) => model.page = page,
-        Msg::ChangeGuidePage(guide_page) => {
-            model.page = Page::Guide;
-            model.guide_page = guide_page;
+        Msg::ChangePage(page) => {
+            model.page = page;
+            model.changelog_release = None;
+        }
+        Msg::ChangeGuideVersion(version) => {
+            // Results index into the old version's sections.
+            model.search_query.clear();
+            model.search_results.clear();
+            model.show(Route::Guide { version });
+            if model.content_loaded() {
+                orders.force_render_now().send_msg(Msg::ContentRendered);
+            }
+            orders.send_msg(Msg::LoadContent);
+        }
+        Msg::ChangeGuidePage {
+            version,
+            slug,
+            heading,
+        } => {
+            // Eg following a search result; close the results, and the menu.
+            model.search_query.clear();
+            model.search_results.clear();
+            model.menu_open = false;
+
+            model.show(Route::guide_section(version.as_deref(), &slug));
+
+            // The heading only exists once the section's loaded, and rendered.
+            model.scroll_to = heading;
+            if model.content_loaded() {
+                orders.force_render_now().send_msg(Msg::ContentRendered);
+            }
+            orders.send_msg(Msg::LoadContent);
+        }
+        Msg::Header(header::Msg::SelectGuideVersion(name)) => {
+            if let Some(index) = book::version(&name) {
+                // Stay on the same section, if the other version has it.
+                let section = book::VERSIONS[index].section_or_first(&model.guide_page);
+                navigate(&Route::guide_section(route_version(index), section.slug), orders);
+            }
+        }
+        Msg::PrevSection | Msg::NextSection => {
+            let (prev, next) = neighbours(&model.guide_sections, &model.guide_page);
+            let section = if let Msg::PrevSection = msg { prev } else { next };
+            match section {
+                Some(section) => {
+                    let version = route_version(model.guide_version);
+                    navigate(&Route::guide_section(version, &section.path), orders);
+                }
+                None => {
+                    orders.skip();
+                }
+            }
+        }
+        Msg::KeyDown(event) => {
+            // Leave typing, and the browser's own shortcuts, alone.
+            let typing = event.target().is_some_and(|target| takes_keys(&target));
+            let modified = event.ctrl_key() || event.alt_key() || event.meta_key();
+
+            orders.skip();
+            match event.key().as_str() {
+                "ArrowLeft" | "k" if !typing && !modified => {
+                    orders.send_msg(Msg::PrevSection);
+                }
+                "ArrowRight" | "j" if !typing && !modified => {
+                    orders.send_msg(Msg::NextSection);
+                }
+                _ => (),
+            }
+        }
+        Msg::ContentRendered => {
+            if let Some(id) = model.scroll_to.take() {
+                if let Some(heading) = seed::document().get_element_by_id(&id) {
+                    heading.scroll_into_view();
+                }
+            }
+            orders.skip();
+        }
+        Msg::Guide(guide::Msg::Click(event)) => {
+            let block = event
+                .target()
+                .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                .and_then(|target| target.closest(".code-copy").ok().and_then(|b| b))
+                .and_then(|button| button.closest("div.sourceCode").ok().and_then(|b| b));
+
+            orders.skip();
+            if let Some(block) = block {
+                let copied = JsFuture::from(copy_code(&block))
+                    .map(|_| Msg::Copied(true))
+                    .map_err(|_| Msg::Copied(false));
+                orders.perform_cmd(copied);
+            }
+        }
+        Msg::Copied(copied) => {
+            let id = model.toast.as_ref().map_or(0, |(id, _)| id + 1);
+            let text = if copied { "Copied" } else { "Couldn't copy" };
+            model.toast = Some((id, text.into()));
+            orders.perform_cmd(after(TOAST_MS, Msg::HideToast(id)));
+        }
+        Msg::HideToast(id) => {
+            // Unless it's been replaced by a newer one.
+            if model.toast.as_ref().is_some_and(|(current, _)| *current == id) {
+                model.toast = None;
+            } else {
+                orders.skip();
+            }
+        }
+        Msg::LoadContent | Msg::Guide(guide::Msg::Retry) => {
+            let url = model.content_url();
+            let needed = match model.guide_content.get(&url) {
+                Some(Content::Loading) | Some(Content::Loaded(_)) => false,
+                Some(Content::Failed(_)) | None => true,
+            };
+
+            if let (Page::Guide, true) = (&model.page, needed) {
+                model.guide_content.insert(url.clone(), Content::Loading);
+                orders.perform_cmd(fetch_content(url));
+            } else {
+                orders.skip();
+            }
+        }
+        Msg::ContentFetched { url, result } => {
+            let content = match result {
+                Ok(html) => Content::Loaded(html),
+                Err(reason) => Content::Failed(reason),
+            };
+            model.guide_content.insert(url.clone(), content);
+
+            if url == model.content_url() && model.content_loaded() {
+                orders.force_render_now().send_msg(Msg::ContentRendered);
+            }
+        }
+        Msg::ChangeRelease(version) => model.show(Route::ChangelogRelease(version)),
+        Msg::Header(header::Msg::ChangeTheme(theme)) => {
+            model.theme = theme;
+            theme.apply();
+            theme.store();
+        }
+        Msg::Resized => {
+            let was_narrow = model.narrow();
+            if let Some(width) = window_width() {
+                model.window_width = width;
+            }
+            // Only re-render when crossing the breakpoint.
+            if model.narrow() == was_narrow {
+                orders.skip();
+            } else if !model.narrow() {
+                model.menu_open = false;
+            }
+        }
+        Msg::Header(header::Msg::Search(query)) => {
+            model.search_results = search::search(&query, model.guide_version);
+            model.search_query = query;
+        }
+        Msg::Guide(guide::Msg::ToggleMenu) => model.menu_open = !model.menu_open,
+        Msg::Guide(guide::Msg::Demo(msg)) => demos::update(msg, &mut model.demos),
+        Msg::Changelog(components::changelog::Msg::ToggleBreakingOnly) => {
+            model.breaking_only = !model.breaking_only
+        }
+        Msg::Changelog(components::changelog::Msg::ToggleRelease(version)) => {
+            if !model.collapsed_releases.remove(&version) {
+                model.collapsed_releases.insert(version);
+            }
+        }
+        Msg::UpdateAvailable => model.update_available = true,
+        Msg::Reload => {
+            offline::activate_update();
+            orders.skip();
         }
     }
-}
 
-fn header(_version: &str) -> Node<Msg> {
-    let link_style = style! {
-        "margin-left" => unit!(20, px);
-        "margin-right" => unit!(20, px);
-        "font-weight" => "bold";
-        "font-size" => unit!(1.2, em);
-        "color" => "black";
-        "cursor" => "pointer";
-    };
+    // Eg for the tab, history entries, and shared links' previews.
+    let new_meta = Meta::of(model);
+    if new_meta != meta {
+        new_meta.apply();
+    }
+}
 
-    header![
-        style! {"display" => "flex"; "justify-content" => "flex-end"},
-        ul![
-            a![&link_style, "Guide", attrs! {At::Href => "/guide"}],
-            a![&link_style, "Changelog", attrs! {At::Href => "/changelog"}],
-            a![
-                &link_style,
-                "Repo",
-                attrs! {At::Href => "https://github.com/David-OConnor/seed"}
-            ],
-            a![
-                &link_style,
-                "Quickstart repo",
-                attrs! {At::Href => "https://github.com/David-OConnor/seed-quickstart"}
-            ],
-            a![
-                &link_style,
-                "Crate",
-                attrs! {At::Href => "https://crates.io/crates/seed"}
-            ],
-            a![
-                &link_style,
-                "API docs",
-                attrs! {At::Href => "https://docs.rs/seed"}
-            ]
-        ]
-    ]
+/// The prerendered page's section HTML, if it has one, and where it's fetched from; so the
+/// app can show it straight away, rather than fetching it again. Call before replacing the
+/// prerendered page.
+fn prerendered_content() -> Option<(String, String)> {
+    let selector = format!("#app [{}]", guide::CONTENT_URL_ATTR);
+    let section = seed::document().query_selector(&selector).ok()??;
+    let url = section.get_attribute(guide::CONTENT_URL_ATTR)?;
+
+    let copy = section.clone_node_with_deep(true).ok()?.dyn_into::<web_sys::Element>().ok()?;
+    demos::restore_markers(&copy);
+    Some((url, copy.inner_html()))
 }
 
-fn title() -> Node<Msg> {
-    div![
-        style! {
-        // todo look up areas
-        "display" => "grid";
-        "grid-template-rows" => "auto 160px";
-        "grid-template-columns" => "1fr 1fr 1fr";
-        "text-align" => "center";
-        "align-items" => "center";
-        },
-        div![
-            style! {"grid-row" => "1/2"; "grid-column" => "1 / 4"},
-            img![
-                attrs! {At::Src => "/public/seed_logo.svg"; At::Width => 256; At::Alt => "Seed"},
-                style! {"margin-top" => unit!(30, px)},
-            ],
-            h2!["A Rust framework for creating web apps"],
-            h3!["Latest release: 0.4.2 (Nov 05, 2019)"],
-        ],
-        div![
-            style! {"grid-row" => "2/3"; "grid-column" => "1 / 2"},
-            h2!["Expressive view syntax"]
-        ],
-        div![
-            style! {"grid-row" => "2/3"; "grid-column" => "2 / 3"},
-            h2!["Compile-time error checking"]
-        ],
-        div![
-            style! {"grid-row" => "2/3"; "grid-column" => "3 / 4"},
-            h2!["Clean architecture"]
-        ],
-    ]
+fn fetch_content(url: String) -> impl Future<Item = Msg, Error = Msg> {
+    Request::new(url.clone())
+        .method(Method::Get)
+        .fetch_string_data(move |result| Msg::ContentFetched {
+            url,
+            result: result.map_err(|reason| format!("{:?}", reason)),
+        })
 }
 
-fn guide(sections: &[GuideSection], guide_page: &str) -> Node<Msg> {
-    let menu_item_style = style! {
-        "display" => "flex";  // So we can vertically center
-        "align-items" => "center";
-        "padding" => unit!(4, px);
-        "cursor" => "pointer";
-        "height" => unit!(40, px);
-        "margin-bottom" => 0;
-        "width" => unit!(100, %);
-        "color" => "black";
-        "font-size" => unit!(1.2, em);
-    };
+/// How long toasts are shown for, in milliseconds.
+const TOAST_MS: i32 = 2000;
 
-    let menu_items = sections.iter().map(|s| {
-        h4![
-            &menu_item_style,
-            attrs! {
-                At::Class => if s.path == guide_page {"guide-menu-selected"} else {"guide-menu"};
-                At::Href => "/guide/".to_string() + &s.path
-            },
-            s.title
-        ]
+/// Resolves to `msg` after a delay.
+fn after(ms: i32, msg: Msg) -> impl Future<Item = Msg, Error = Msg> {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        seed::window()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, ms)
+            .expect("Problem setting timeout");
     });
+    let on_error = msg.clone();
+    JsFuture::from(promise).map(|_| msg).map_err(|_| on_error)
+}
 
-    div![
-        style! {
-            "display" => "grid";
-            "grid-template-columns" => "200px auto";
-            "color" => "black";
-            "grid-auto-rows" => "1fr";
-            "align-items" => "start";
-        },
-        div![
-            style! {"display" => "flex"; "flex-direction" => "column";
-            "grid-column" => "1 / 2";
-             "justify-content" => "flex-start";
-            "padding" => unit!(10, px);},
-            menu_items
-        ],
-        div![
-            class!["guide"],
-            style! {
-                "display" => "flex";
-                "flex-direction" => "column";
-                "grid-column" => "2 / 3";
-                "padding" => unit!(80, px);
-            },
-            raw![&sections.iter().find(|s| s.path == guide_page).unwrap().content],
-        ]
-    ]
+/// Copy a code block's code to the clipboard; the promise resolves if it worked. Uses the
+/// clipboard API where there is one, and otherwise selects the code and runs the copy
+/// command; eg the clipboard API needs HTTPS.
+fn copy_code(block: &web_sys::Element) -> js_sys::Promise {
+    let pre = match block.query_selector("pre") {
+        Ok(Some(pre)) => pre,
+        _ => return js_sys::Promise::reject(&JsValue::UNDEFINED),
+    };
+    let text = pre.text_content().unwrap_or_default();
+
+    // `web_sys`' `Clipboard` is unstable, so find `navigator.clipboard.writeText` ourselves.
+    let navigator = seed::window().navigator();
+    let write_text = js_sys::Reflect::get(&navigator, &"clipboard".into())
+        .ok()
+        .filter(|clipboard| clipboard.is_object())
+        .and_then(|clipboard| {
+            let write_text = js_sys::Reflect::get(&clipboard, &"writeText".into()).ok()?;
+            let write_text = write_text.dyn_into::<js_sys::Function>().ok()?;
+            write_text.call1(&clipboard, &text.into()).ok()?.dyn_into::<js_sys::Promise>().ok()
+        });
+
+    match write_text {
+        Some(promise) => promise,
+        None if exec_copy(&pre) => js_sys::Promise::resolve(&JsValue::UNDEFINED),
+        None => js_sys::Promise::reject(&JsValue::UNDEFINED),
+    }
 }
 
-fn changelog() -> Node<Msg> {
-    let entries = span![ md!(
-"
-## v0.4.2
-- Added an `Init` struct, which can help with initial routing (Breaking)
-- The `routes` function now returns an `Option<Msg>` (Breaking)
-- Updated `Tag::from()` to accept more input types
-- `style!` now accepts also `Option<impl ToString>`
-- Fixed a bug affecting element render order
-- Added a `hashchange` listener
-- Improved error-handling
-- Tweaked bootstrap order so that `main_el_vdom` is initialized first (internal)
-- Macro `custom!` checks if you set tag, and panics when you forget
-- Fixed a bug with children being absent from cloned elements
-- Improved debugging
-- Fixed a namespace bug with adding children to `Svg` elements
-- Fixed a bug affecting Safari
-- Added `seed::html_document()` and `seed::cookies` convenience functions
-
-## v0.4.1
-- Added more SVG `At` variants
-- Added the `St` enum, for style keys; similar to `At`
-- Improved ergonomics of `add_child`, `add_attr`, `add_class`,
-`add_style`, `replace_text`, and `add_text`, `Node` methods
-
-## v0.4.0
-- `ElContainer`, imported in prelude, renamed to `View`. (Breaking)
-- Internal refactor of `El`: Now wrapped in `Node`, along with
-`Empty` and `Text`. Creation macros return `Node(Element)`. (Breaking)
-- Changed the way special attributes like `disabled`, `autofocus`, and
-`checked` are handled (Breaking)
-- `MessageMapper` now accepts closures
-- `Orders` is a trait now instead of a struct. (Breaking)
-- Significant changes to MessageMapper
-- Orders has new methods, `clone_app` and `msg_mapper` which can allow access to app instance.
-- Added more SVG element macros
-- Several minor bux fixes
-- Examples updated to reflect these changes
-- Improvements to Fetch API, especially regarding error handling
-and deserialization
-
-## v0.3.7
-- `routes` now accepts `Url` instead of `&Url` (Breaking)
-- Improvements to fetch API
-- Added `raw!`, `md!`, and `plain!` macros that alias `El::from_html`, `El::from_markdown`,
-and `El::new_text` respectively
-- `Attrs!` and `Style!` macros can now use commas and whitespace as separators,
-in addition to semicolons
-- Fixed typos in a few attributes (Breaking)
-- Fixed a bug where an HTML namespace was applied to raw html/markdown elements
-- New conditional syntax added in `class!` macro, similar to `Elm`'s `classList`
-- `Listener` now implements `MessageMapper`
-- `El methods` `add_child`, `add_style`, `add_attr`, and `set_text` now return the elements,
-allowing chaining
-- Fixed a bug with `set_text`. Renamed to `replace_text`. Added `add_text`, which adds
-a text node, but doesn't remove existing ones. Added `add_class`. (Breaking)
-
-## v0.3.6
-- Fetch module and API heavily changed (breaking)
-- Added support for `request​Animation​Frame`, which improves render performance,
-especially for animations
-- Styles no longer implicitly add `px`. Added `unit!` macro in its place
-- `Map` can now be used directly in elements, without needing to annotate type and collect
-(ie for child `Elements`, and `Listener`s)
-- Fixed a bug where `empty` elements at the top-level were rendering in the wrong order
-- Added an `empty!` macro, which is similar to `seed::empty`
-- Attributes and style now retain order
-
-## v0.3.5
-- Fixed a bug where view functions returning `Vec<El>` weren't rendering properly
-- Fixed a typo with the `viewBox` attribute
-
-## v0.3.4
-- The `update` fn now accepts a (new) `Orders` struct, and returns nothing. Renders occur implicitly,
-with the option to skip rendering, update with an additional message, or perform an asynchronous
-action. (Breaking)
-- `.mount()` now accepts elements. Deprecated `.mount_el()`
-- The `log` function and macro now support items which implement `Debug`
-- Removed deprecated `routing::push_path` function (breaking)
-
-## v0.3.3
-- Added `seed::update` function, which allows custom events, and updates from JS.
-
-## v0.3.2
-- Top level view functions can now return `Vec<El<Ms>>`, `El<Ms>`, or something else implementing
-the new ElContainer trait
-
-## v0.3.1
-- Top level view functions now return `Vec<El<Ms>>` instead of `El<Ms>`, mounted directly to
- the mount point. (Breaking)
-- `push_route()` can now accept a `Vec<&str>`, depreciating `push_path()`
-- Fixed a bug where window events couldn't be enabled on initialization
-
-## v0.3.0
-- `update` function now takes a mutable ref of the model. (Breaking)
-- `Update` (update's return type) is now a struct. (Breaking)
-- Async, etc events are now handled through messages, instead of passing `App`
-through the view func. (breaking)
-- Fixed some bugs with empty elements
-- Internal code cleanup
-- Added commented-out release command to example build files
-- Added more tests
-
-## v0.2.10
-- Routing can be triggered by clicking any element containing a `Href` attribute
-with value as a relative link
-- Internal links no longer trigger a page refresh
-- Models no longer need to implement `Clone`
-- Fixed a bug introduced in 0.2.9 for `select` elements
-
-## v0.2.9
-- Added a `RenderThen` option to `Update`, which allows chaining update messages
-- Added a `.model` method to `Update`, allowing for cleaner recursion in updates
-- Improved controlled-comonent (sync fields with model) logic
-
-## v0.2.8
-- Reflowed `El::from_html` and `El::from_markdown` to return `Vec`s of `El`s, instead of wrapping
-them in a single span.
-- Added `set_timeout` wrapper
-- Improved support for SVG and namespaces
-
-## v0.2.7
-- Fixed a bug where `line!` macro interfered with builtin
-- Fixed a bug with routing search (ie `?`)
-
-## v0.2.6
-- Fixed a bug where children would render out-of-order
-- Improved vdom diffing logic
-
-## v0.2.5
-- Attributes and Events now can use `At` and `Ev` enums
-- Routing overhauled; modelled after react-reason. Cleaner syntax, and more flexible
-- Input, Textarea, and Select elements are now \"controlled\" - they always
-stay in sync with the model.
-- index.html file updated in examples and quickstart to use relative paths,
-which fixes landing-page routing
-
-## v0.2.4
-- Changed render func to use a new pattern (Breaking)
-- Default mount point added: \"app\" for element id
-- View func now takes a ref to the model instead of the model itself
-- Routing refactored; now works dynamically
-- Update function now returns an enum that returns Render or Skip,
-to allow conditional rendering (Breaking)
-- Elements can now store more than 1 text node
-
-## V0.2.3
-- Fixed a bug where initially-empty text won't update
-- Added more tests
-- Exposed web_sys Document and Window in top level of Seed create, with .expect
-- Modified build scripts to keep the wasm output name fixed at 'package', simplifying example/quickstart renames
-- Tests now work in Windows due to update in wasm-pack
-
-## V0.2.2
-- Overhaul of fetch module
-- Added server-integration example
-
-## V0.2.1
-- Added support for custom tags
-- Added `class!` and `id!` convenience macros for setting style
-
-## v0.2.0
-
-- Added high-level fetch api
-- Added routing
-- Added element lifecycles (did_mount, did_update, will_unmount)
-- Added support for updating state outside events
-- Added server_interaction, and homepage (this site) examples
-
-## v0.1.0
-
-- Initial release
-
-"
-    ), style! {
-       "grid-column" => "2 / 3";
-    } ];
+/// Copy an element's text by selecting it, and running the copy command. Returns whether it
+/// worked.
+fn exec_copy(element: &web_sys::Element) -> bool {
+    let document = match seed::document().dyn_into::<web_sys::HtmlDocument>() {
+        Ok(document) => document,
+        Err(_) => return false,
+    };
+    let selection = match seed::window().get_selection() {
+        Ok(Some(selection)) => selection,
+        _ => return false,
+    };
 
-    div![
-        class!["guide"],
-        style! {
-            "display" => "grid";
-            "grid-template-columns" => "1fr 2fr 1fr";
+    let copied = selection.select_all_children(element).is_ok()
+        && document.exec_command("copy").unwrap_or(false);
+    selection.remove_all_ranges().ok();
+    copied
+}
 
-            "padding" => unit!(50, px);
-            "color" => "black";
-        },
-        entries,
-    ]
+/// Whether an element uses the keys itself, like an input, the version picker, or
+/// editable content; it has the focus when a key's pressed.
+fn takes_keys(target: &web_sys::EventTarget) -> bool {
+    target.has_type::<web_sys::HtmlInputElement>()
+        || target.has_type::<web_sys::HtmlSelectElement>()
+        || target.has_type::<web_sys::HtmlTextAreaElement>()
+        || target
+            .dyn_ref::<web_sys::HtmlElement>()
+            .is_some_and(web_sys::HtmlElement::is_content_editable)
 }
 
-fn footer() -> Node<Msg> {
-    footer![
-        style! {"display" => "flex"; "justify-content" => "center"},
-        h4!["© 2019 David O'Connor"]
-    ]
+/// Go to a route, as following a link to it would.
+fn navigate(route: &Route, orders: &mut impl Orders<Msg>) {
+    let url = seed::Url::new(route.path());
+    seed::push_route(url.clone());
+    orders.skip();
+    if let Some(msg) = routes(url) {
+        orders.send_msg(msg);
+    }
 }
 
 fn view(model: &Model) -> Node<Msg> {
-    let version = "0.3.1";
+    let version = changelog::LATEST_VERSION;
+    let guide_version = route_version(model.guide_version);
+
     div![
         style! {
             "display" => "flex";
             "flex-direction" => "column";
         },
-        section![header(version)],
-        section![title()],
-        section![match model.page {
-            Page::Guide => guide(&model.guide_sections, &model.guide_page),
-            Page::Changelog => changelog(),
+        section![header::view(&header::Props {
+            version,
+            search_query: &model.search_query,
+            guide_versions: book::VERSIONS,
+            guide_version: model.guide_version,
+            theme: model.theme,
+        })
+        .map_message(Msg::Header)],
+        section![search_results::view(&search_results::Props {
+            version: guide_version,
+            sections: &model.guide_sections,
+            query: &model.search_query,
+            results: &model.search_results,
+        })],
+        section![title::view(&title::Props {
+            version,
+            release_date: changelog::LATEST_RELEASE_DATE,
+            narrow: model.narrow(),
+        })],
+        section![match &model.page {
+            Page::Guide => guide::view(&guide::Props {
+                version: guide_version,
+                sections: &model.guide_sections,
+                guide_page: &model.guide_page,
+                content: model.guide_content.get(&model.content_url()),
+                content_url: &model.content_url(),
+                demos: &model.demos,
+                narrow: model.narrow(),
+                menu_open: model.menu_open,
+            })
+            .map_message(Msg::Guide),
+            Page::Changelog => components::changelog::view(&components::changelog::Props {
+                releases: changelog::RELEASES,
+                release: model.changelog_release.as_deref(),
+                breaking_only: model.breaking_only,
+                collapsed: &model.collapsed_releases,
+                feed_url: changelog::FEED_URL,
+            })
+            .map_message(Msg::Changelog),
+            Page::NotFound(path) => not_found::view(&not_found::Props {
+                version: guide_version,
+                sections: &model.guide_sections,
+                path,
+            }),
         }],
-        section![footer()],
+        section![footer::view()],
+        match &model.toast {
+            Some((_, text)) => div![class!["toast"], text],
+            None => empty![],
+        },
+        if model.update_available {
+            div![
+                class!["update-banner"],
+                "A new version of this site is available.",
+                button!["Reload", simple_ev(Ev::Click, Msg::Reload)]
+            ]
+        } else {
+            empty![]
+        },
     ]
 }
 
+/// Keyboard shortcuts for paging through the guide, keeping the layout in step with the
+/// window's width, and the live demos' listeners.
+fn window_events(model: &Model) -> Vec<seed::events::Listener<Msg>> {
+    let mut events = vec![simple_ev(Ev::Resize, Msg::Resized)];
+    if let Page::Guide = model.page {
+        events.push(keyboard_ev(Ev::KeyDown, Msg::KeyDown));
+        events.extend(
+            demos::window_events(&model.demos)
+                .into_iter()
+                .map(|listener| listener.map_message(|msg| Msg::Guide(guide::Msg::Demo(msg)))),
+        );
+    }
+    events
+}
+
 #[allow(clippy::needless_pass_by_value)]
 fn routes(url: seed::Url) -> Option<Msg> {
-    Some(match url.path.get(0).map(String::as_str) {
-        Some("guide") => match url.path.get(1).as_ref() {
-            Some(page) => Msg::ChangeGuidePage(page.to_string()),
-            None => Msg::ChangePage(Page::Guide),
+    Some(match Route::from_url(&url) {
+        Route::Guide { version } => Msg::ChangeGuideVersion(version),
+        Route::GuideSection {
+            version,
+            slug,
+            fragment,
+        } => Msg::ChangeGuidePage {
+            version,
+            slug,
+            heading: fragment,
         },
-        Some("changelog") => Msg::ChangePage(Page::Changelog),
-        _ => Msg::ChangePage(Page::Guide),
+        Route::Changelog => Msg::ChangePage(Page::Changelog),
+        Route::ChangelogRelease(version) => Msg::ChangeRelease(version),
+        Route::NotFound(path) => Msg::ChangePage(Page::NotFound(path)),
     })
 }
 
 
 #[wasm_bindgen(start)]
 pub fn render() {
-        seed::App::build(|_, _| Init::new(Model::default()), update, view)
+        // Replace the prerendered page, rather than rendering alongside it; keeping its
+        // section, so the first render has it too.
+        let prerendered = prerendered_content();
+        if let Some(app) = seed::document().get_element_by_id("app") {
+            app.set_inner_html("");
+        }
+
+        let theme = Theme::stored();
+        theme.apply();
+
+        let app = seed::App::build(
+            move |url, _| {
+                let mut model = Model { theme, ..Model::default() };
+                if let Some(width) = window_width() {
+                    model.window_width = width;
+                }
+                // Start on the URL's page, as it was prerendered, rather than on the default
+                // page until routing changes it.
+                model.show(Route::from_url(&url));
+                if let Some((url, html)) = prerendered {
+                    model.guide_content.insert(url, Content::Loaded(html));
+                }
+                Meta::of(&model).apply();
+                Init::new(model)
+            },
+            update,
+            view,
+        )
 
         .routes(routes)
+        .window_events(window_events)
         .build_and_start();
+
+        offline::register(move || app.update(Msg::UpdateAvailable));
 }
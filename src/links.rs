@@ -0,0 +1,295 @@
+//! Checks the guide's links, as part of `cargo test`. Links within the site are checked
+//! against its routes, and each section's heading ids. Links to the API docs on docs.rs are
+//! checked against the items of that version of Seed, from its rustdoc JSON, vendored as
+//! `rustdoc/seed-<version>.json`. Older versions without one are skipped, with a note;
+//! the latest release's must be vendored. Other links aren't checked, since tests don't
+//! have a network.
+//!
+//! To vendor a version's rustdoc JSON, run
+//! `cargo +nightly rustdoc -- -Z unstable-options --output-format json` in a checkout of
+//! Seed at that version, and copy `target/doc/seed.json`.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+};
+
+use seed::Url;
+use serde_json::Value;
+
+use crate::{book, changelog, prerender::SITE, route::Route};
+
+/// A link in the guide, listed by `build.rs`.
+struct Link {
+    /// The guide version and section it's in.
+    version: &'static str,
+    slug: &'static str,
+    /// Where it is, eg `markdown/0.4/routing.md`.
+    file: &'static str,
+    line: usize,
+    /// As shown on the site; docs.rs links point at the version the guide documents.
+    url: &'static str,
+}
+
+include!(concat!(env!("OUT_DIR"), "/links.rs"));
+
+const DOCS_RS: &str = "https://docs.rs/seed/";
+
+/// Why a link within the site is broken, if it is.
+fn check_internal(link: &Link) -> Option<String> {
+    let url = if link.url.starts_with('#') {
+        // A heading in the link's own section.
+        Route::guide_section(Some(link.version), link.slug).to_url() + link.url
+    } else {
+        link.url.trim_start_matches(SITE).to_string()
+    };
+
+    let mut parts = url.splitn(2, '#');
+    let path = parts.next().unwrap_or("");
+    let mut parsed = Url::new(path.split('/').filter(|p| !p.is_empty()).collect());
+    parsed.hash = parts.next().map(String::from);
+
+    match Route::from_url(&parsed) {
+        Route::NotFound(_) => Some("no such page".into()),
+        Route::Guide { version } => match version {
+            Some(version) if book::version(&version).is_none() => {
+                Some(format!("no {} version of the guide", version))
+            }
+            _ => None,
+        },
+        Route::GuideSection {
+            version,
+            slug,
+            fragment,
+        } => {
+            let guide = match &version {
+                Some(version) => match book::version(version) {
+                    Some(i) => &book::VERSIONS[i],
+                    None => return Some(format!("no {} version of the guide", version)),
+                },
+                None => book::latest(),
+            };
+            let section = match guide.sections.iter().find(|s| s.slug == slug) {
+                Some(section) => section,
+                None => return Some(format!("no section `{}` in the {} guide", slug, guide.name)),
+            };
+            match fragment {
+                Some(id) if !section.headings.iter().any(|h| h.id == id) => {
+                    Some(format!("no heading `#{}` in `{}`", id, slug))
+                }
+                _ => None,
+            }
+        }
+        Route::Changelog => None,
+        Route::ChangelogRelease(version) => {
+            if changelog::RELEASES.iter().any(|r| r.version == version) {
+                None
+            } else {
+                Some(format!("no release {} in the changelog", version))
+            }
+        }
+    }
+}
+
+/// A version of Seed's documented items, from its rustdoc JSON.
+struct Api {
+    /// Each item's paths, eg `seed::routing::Url`, with their kinds, eg `struct`. A path can
+    /// have more than one, like the `empty` function and macro.
+    paths: HashMap<String, Vec<String>>,
+    /// The names of all items, including methods, variants and fields, for checking
+    /// fragments like `#method.build`.
+    names: HashSet<String>,
+}
+
+impl Api {
+    /// `None` if the version's JSON isn't vendored.
+    fn load(version: &str) -> Option<Self> {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("rustdoc")
+            .join(format!("seed-{}.json", version));
+        let json: Value = serde_json::from_str(&fs::read_to_string(&path).ok()?)
+            .unwrap_or_else(|_| panic!("Problem parsing {}", path.display()));
+
+        let summaries = json["paths"].as_object().expect("rustdoc JSON has no paths");
+        let index = json["index"].as_object().expect("rustdoc JSON has no index");
+        // An item's path, and kind, by id.
+        let summary = |id: &Value| {
+            let item = summaries.get(&id_key(id)?)?;
+            let path: Vec<&str> = item["path"]
+                .as_array()?
+                .iter()
+                .map(Value::as_str)
+                .collect::<Option<_>>()?;
+            Some((path.join("::"), item["kind"].as_str()?.to_string()))
+        };
+
+        let mut paths: HashMap<String, Vec<String>> = HashMap::new();
+        for (id, item) in summaries {
+            if item["crate_id"] == 0 {
+                if let Some((path, kind)) = summary(&Value::String(id.clone())) {
+                    paths.entry(path).or_default().push(kind);
+                }
+            }
+        }
+        // Items re-exported from private modules get a page where they're re-exported, eg
+        // `seed::App` from `seed::vdom::App`.
+        for (id, item) in index {
+            let items = item["inner"]["module"]["items"].as_array();
+            let (module, items) = match (summary(&Value::String(id.clone())), items) {
+                (Some((module, _)), Some(items)) => (module, items),
+                _ => continue,
+            };
+            for item in items.iter().filter_map(|id| index.get(&id_key(id)?)) {
+                let export = &item["inner"]["use"];
+                if export["is_glob"] == true {
+                    continue;
+                }
+                if let (Some(name), Some((_, kind))) =
+                    (export["name"].as_str(), summary(&export["id"]))
+                {
+                    let path = format!("{}::{}", module, name);
+                    paths.entry(path).or_default().push(kind);
+                }
+            }
+        }
+
+        let names = index
+            .values()
+            .filter_map(|item| item["name"].as_str().map(String::from))
+            .collect();
+
+        Some(Self { paths, names })
+    }
+}
+
+/// An item id as a key into rustdoc JSON's `index` and `paths`; ids are numbers in newer
+/// formats, and strings in older ones.
+fn id_key(id: &Value) -> Option<String> {
+    match id {
+        Value::Number(n) => Some(n.to_string()),
+        Value::String(s) => Some(s.clone()),
+        _ => None,
+    }
+}
+
+/// Whether a docs.rs page's kind, eg `fn` in `fn.push_route.html`, is rustdoc JSON's `kind`.
+fn same_kind(page_kind: &str, kind: &str) -> bool {
+    match page_kind {
+        "fn" => kind == "function",
+        "type" => kind == "type_alias" || kind == "typedef",
+        "derive" | "attr" => kind.starts_with("proc_"),
+        _ => page_kind == kind,
+    }
+}
+
+/// Why a docs.rs link is broken, if it is; `page` is its path after the version, eg
+/// `seed/routing/struct.Url.html#method.new`.
+fn check_docs(page: &str, api: &Api) -> Option<String> {
+    let mut parts = page.splitn(2, '#');
+    let mut segments: Vec<&str> = parts
+        .next()
+        .unwrap_or("")
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .collect();
+    let fragment = parts.next();
+
+    // Eg `struct.Url.html`; modules' pages are `index.html`.
+    let kind = match segments.last().map(|s| s.trim_end_matches(".html")) {
+        Some("index") | None => {
+            segments.pop();
+            "module"
+        }
+        Some(file) => match file.find('.') {
+            Some(i) => {
+                let (kind, name) = (&file[..i], &file[i + 1..]);
+                segments.pop();
+                segments.push(name);
+                kind
+            }
+            None => "module",
+        },
+    };
+    if segments.is_empty() {
+        // The docs' front page.
+        return None;
+    }
+
+    let path = segments.join("::");
+    match api.paths.get(&path) {
+        None => Some(format!("no item `{}`", path)),
+        Some(found) if !found.iter().any(|found| same_kind(kind, found)) => {
+            Some(format!("`{}` is a {}, not `{}`", path, found.join(" and "), kind))
+        }
+        _ => {
+            // Eg `method.build`.
+            let member = fragment.and_then(|f| f.split_once('.')).map(|(_, name)| name);
+            match member {
+                Some(name) if !api.names.contains(name) => {
+                    Some(format!("no `{}` in `{}`", name, path))
+                }
+                _ => None,
+            }
+        }
+    }
+}
+
+fn report(broken: &[String]) {
+    assert!(
+        broken.is_empty(),
+        "{} broken links:\n{}",
+        broken.len(),
+        broken.join("\n")
+    );
+}
+
+#[test]
+fn internal_links() {
+    let broken: Vec<String> = LINKS
+        .iter()
+        .filter(|l| l.url.starts_with('/') || l.url.starts_with('#') || l.url.starts_with(SITE))
+        .filter_map(|l| {
+            check_internal(l).map(|reason| format!("{}:{}: {}: {}", l.file, l.line, l.url, reason))
+        })
+        .collect();
+    report(&broken);
+}
+
+#[test]
+fn docs_rs_links() {
+    let mut apis: HashMap<&str, Option<Api>> = HashMap::new();
+    let mut broken = Vec::new();
+
+    for link in LINKS.iter().filter(|l| l.url.starts_with(DOCS_RS)) {
+        // Eg `0.4.2/seed/struct.App.html`.
+        let rest = &link.url[DOCS_RS.len()..];
+        let mut parts = rest.splitn(2, '/');
+        let version = parts.next().unwrap_or("");
+        let page = parts.next().unwrap_or("");
+
+        let api = apis.entry(version).or_insert_with(|| {
+            let api = Api::load(version);
+            if api.is_none() {
+                // The current guide's links must be checked.
+                assert_ne!(
+                    version,
+                    changelog::LATEST_VERSION,
+                    "rustdoc/seed-{}.json isn't vendored",
+                    version
+                );
+                eprintln!(
+                    "Not checking links to Seed {}'s docs: rustdoc/seed-{}.json isn't vendored",
+                    version, version
+                );
+            }
+            api
+        });
+        if let Some(api) = api {
+            if let Some(reason) = check_docs(page, api) {
+                broken.push(format!("{}:{}: {}: {}", link.file, link.line, link.url, reason));
+            }
+        }
+    }
+    report(&broken);
+}
@@ -0,0 +1,96 @@
+//! The page's title and description, for browser history, bookmarks, and previews of shared
+//! links. The app sets them on the document whenever its page changes; prerendering writes
+//! each page's into its HTML, in place of `index.html`'s, for crawlers that don't run the wasm.
+
+use crate::{book, Model, Page};
+
+/// `index.html`'s description, for pages without one of their own.
+const DESCRIPTION: &str = "A frontend framework for Rust, via WebAssembly";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Meta {
+    pub title: String,
+    pub description: String,
+}
+
+impl Default for Meta {
+    fn default() -> Self {
+        Self::new("Seed", DESCRIPTION)
+    }
+}
+
+impl Meta {
+    fn new(title: &str, description: &str) -> Self {
+        Self {
+            title: title.into(),
+            description: description.into(),
+        }
+    }
+
+    /// The current page's.
+    pub fn of(model: &Model) -> Self {
+        match &model.page {
+            Page::Guide => {
+                let version = &book::VERSIONS[model.guide_version];
+                let section = match version.sections.iter().find(|s| s.slug == model.guide_page) {
+                    Some(section) => section,
+                    None => return Self::default(),
+                };
+                let title = if model.guide_version == 0 {
+                    format!("{} - Seed", section.title)
+                } else {
+                    format!("{} - Seed {} guide", section.title, version.name)
+                };
+                let description = if section.description.is_empty() {
+                    DESCRIPTION
+                } else {
+                    section.description
+                };
+                Self::new(&title, description)
+            }
+            Page::Changelog => match &model.changelog_release {
+                Some(version) => Self::new(
+                    &format!("Seed {} - Changelog", version),
+                    &format!("What changed in Seed {}.", version),
+                ),
+                None => Self::new("Changelog - Seed", "What's changed in each release of Seed."),
+            },
+            Page::NotFound(_) => Self::new("Page not found - Seed", DESCRIPTION),
+        }
+    }
+
+    /// Set the document's title and meta tags; the tags are the ones in `index.html`.
+    pub fn apply(&self) {
+        let document = seed::document();
+        document.set_title(&self.title);
+
+        for (selector, content) in self.tags().iter() {
+            if let Ok(Some(tag)) = document.query_selector(&format!("meta[{}]", selector)) {
+                tag.set_attribute("content", content).ok();
+            }
+        }
+    }
+
+    /// Each meta tag's selector, eg `name="description"`, and content.
+    fn tags(&self) -> [(&'static str, &str); 3] {
+        [
+            (r#"name="description""#, &self.description),
+            (r#"property="og:title""#, &self.title),
+            (r#"property="og:description""#, &self.description),
+        ]
+    }
+
+    /// The title and meta tags' HTML, as laid out in `index.html`'s head.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn head(&self) -> String {
+        let mut head = format!("<title>{}</title>", crate::prerender::escape(&self.title));
+        for (selector, content) in self.tags().iter() {
+            head.push_str(&format!(
+                "\n    <meta {} content=\"{}\">",
+                selector,
+                crate::prerender::escape(content)
+            ));
+        }
+        head
+    }
+}
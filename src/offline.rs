@@ -0,0 +1,89 @@
+//! Reading the site offline, through the service worker the prerender binary writes from
+//! `sw.template.js`. Once installed it serves every page and the files they load from its cache.
+//! When a new version's installed, it waits until the reader chooses to reload, so the page
+//! doesn't change under them; `update` shows a banner offering to.
+
+use std::rc::Rc;
+
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use web_sys::{ServiceWorker, ServiceWorkerRegistration, ServiceWorkerState};
+
+/// Where the service worker's served; at the root, so it covers every page.
+const SCRIPT_URL: &str = "/sw.js";
+
+/// Register the service worker, if the browser supports them; `on_update` is called when
+/// there's a new version of the site waiting.
+pub fn register(on_update: impl Fn() + 'static) {
+    let navigator = seed::window().navigator();
+    if !js_sys::Reflect::has(&navigator, &JsValue::from_str("serviceWorker")).unwrap_or(false) {
+        return;
+    }
+    let on_update = Rc::new(on_update);
+
+    let registered = Closure::wrap(Box::new(move |registration: JsValue| {
+        let registration: ServiceWorkerRegistration = registration.unchecked_into();
+
+        // Eg one that installed on an earlier visit, while this version was open elsewhere.
+        if registration.waiting().is_some() && controlled() {
+            on_update();
+        }
+
+        let on_update = on_update.clone();
+        let found_registration = registration.clone();
+        let update_found = Closure::wrap(Box::new(move || {
+            if let Some(worker) = found_registration.installing() {
+                watch_install(&worker, on_update.clone());
+            }
+        }) as Box<dyn FnMut()>);
+        registration.set_onupdatefound(Some(update_found.as_ref().unchecked_ref()));
+        update_found.forget();
+    }) as Box<dyn FnMut(JsValue)>);
+
+    // Rejected, eg, when the page isn't served over HTTPS; the site works as before.
+    let _ = navigator
+        .service_worker()
+        .register(SCRIPT_URL)
+        .then(&registered);
+    registered.forget();
+}
+
+/// Call `on_update` once an installing worker has installed, if it's replacing another;
+/// the first one to install isn't an update.
+fn watch_install(worker: &ServiceWorker, on_update: Rc<impl Fn() + 'static>) {
+    let installing = worker.clone();
+    let state_changed = Closure::wrap(Box::new(move || {
+        if installing.state() == ServiceWorkerState::Installed && controlled() {
+            on_update();
+        }
+    }) as Box<dyn FnMut()>);
+    worker.set_onstatechange(Some(state_changed.as_ref().unchecked_ref()));
+    state_changed.forget();
+}
+
+/// Whether the page is served by a service worker.
+fn controlled() -> bool {
+    seed::window().navigator().service_worker().controller().is_some()
+}
+
+/// Have the waiting version take over, and reload the page once it has.
+pub fn activate_update() {
+    let container = seed::window().navigator().service_worker();
+
+    let controller_changed = Closure::wrap(Box::new(|| {
+        seed::window().location().reload().ok();
+    }) as Box<dyn FnMut()>);
+    container.set_oncontrollerchange(Some(controller_changed.as_ref().unchecked_ref()));
+    controller_changed.forget();
+
+    let got_registration = Closure::wrap(Box::new(|registration: JsValue| {
+        if registration.is_undefined() {
+            return;
+        }
+        let registration: ServiceWorkerRegistration = registration.unchecked_into();
+        if let Some(worker) = registration.waiting() {
+            worker.post_message(&JsValue::from_str("skip-waiting")).ok();
+        }
+    }) as Box<dyn FnMut(JsValue)>);
+    let _ = container.get_registration().then(&got_registration);
+    got_registration.forget();
+}
@@ -0,0 +1,333 @@
+//! Renders every route's page to static HTML, so there's content before the wasm loads,
+//! and for crawlers and visitors without JavaScript; and the sitemap and releases feed that
+//! index them. `src/bin/prerender.rs` writes it all into `dist`; the wasm app replaces the
+//! pages' markup once it starts.
+
+use seed::prelude::*;
+
+use crate::{
+    book, changelog, components::guide::Content, meta::Meta, route::Route, route_version, view,
+    Model, Msg,
+};
+
+/// Where the site's served, for the sitemap and feed's absolute URLs.
+pub const SITE: &str = "https://seed-rs.org";
+
+pub use crate::changelog::FEED_URL;
+
+/// The tag of the placeholder `raw` returns; its text is written out unescaped.
+const RAW_TAG: &str = "prerender-raw";
+
+/// Elements that can't have children, or a closing tag.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source",
+    "track", "wbr",
+];
+
+/// A prerendered page.
+pub struct RenderedPage {
+    /// Where it's served, without the leading `/`, eg `guide/view`; empty for the root.
+    pub path: String,
+    /// The app's markup, to go inside the mount element.
+    pub html: String,
+    /// Its title and meta tags, to replace `default_head`'s in `index.html`.
+    pub head: String,
+}
+
+/// A placeholder for markup that's already HTML; see `components::raw_html`.
+pub(crate) fn raw<Ms: Clone + 'static>(html: &str) -> Node<Ms> {
+    custom![Tag::Custom(RAW_TAG.into()), html]
+}
+
+/// The title and meta tags `index.html` has, for pages to replace with theirs.
+pub fn default_head() -> String {
+    Meta::default().head()
+}
+
+/// Every route with a page: each version's guide sections, and the changelog's.
+fn routes() -> Vec<Route> {
+    let mut routes = vec![Route::Guide { version: None }];
+    for (i, version) in book::VERSIONS.iter().enumerate() {
+        let name = route_version(i);
+        if name.is_some() {
+            routes.push(Route::Guide {
+                version: name.map(String::from),
+            });
+        }
+        for section in version.sections {
+            routes.push(Route::guide_section(name, section.slug));
+        }
+    }
+    routes.push(Route::Changelog);
+    for release in changelog::RELEASES {
+        routes.push(Route::ChangelogRelease(release.version.into()));
+    }
+    routes
+}
+
+/// Every page: the root, and each route's.
+pub fn pages() -> Vec<RenderedPage> {
+    let mut pages = vec![render(String::new(), Route::Guide { version: None })];
+    pages.extend(routes().into_iter().map(|route| render(route.path().join("/"), route)));
+    pages
+}
+
+/// The page served, with a 404, for files that don't exist, eg a guide section's HTML;
+/// it's the not-found page.
+pub fn not_found() -> RenderedPage {
+    render("404.html".into(), Route::NotFound("404.html".into()))
+}
+
+/// A `sitemap.xml` listing the root and every route.
+pub fn sitemap() -> String {
+    let mut urls = vec![format!("{}/", SITE)];
+    urls.extend(routes().iter().map(|route| format!("{}{}", SITE, route.to_url())));
+
+    let mut xml = String::from(concat!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+        "\n",
+        r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">"#,
+        "\n",
+    ));
+    for url in urls {
+        xml.push_str(&format!("  <url><loc>{}</loc></url>\n", escape(&url)));
+    }
+    xml.push_str("</urlset>\n");
+    xml
+}
+
+/// A `robots.txt` allowing everything, and pointing to the sitemap.
+pub fn robots() -> String {
+    format!("User-agent: *\nAllow: /\n\nSitemap: {}/sitemap.xml\n", SITE)
+}
+
+/// An Atom feed of Seed's releases, newest first. Atom entries need a date, so only the
+/// releases the changelog has a date for get one.
+pub fn feed() -> String {
+    let timestamp = |date: &str| format!("{}T00:00:00Z", date);
+    let newest = changelog::RELEASES.iter().find_map(|r| r.date);
+    let updated = timestamp(newest.unwrap_or("1970-01-01"));
+    let feed_url = format!("{}{}", SITE, changelog::FEED_URL);
+    let changelog_url = format!("{}{}", SITE, Route::Changelog.to_url());
+
+    let mut xml = String::from(concat!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+        "\n",
+        r#"<feed xmlns="http://www.w3.org/2005/Atom">"#,
+        "\n",
+    ));
+    xml.push_str(&format!(
+        concat!(
+            "  <title>Seed releases</title>\n",
+            "  <id>{}</id>\n",
+            "  <link rel=\"self\" href=\"{}\"/>\n",
+            "  <link rel=\"alternate\" type=\"text/html\" href=\"{}\"/>\n",
+            "  <updated>{}</updated>\n",
+            "  <author><name>The Seed contributors</name></author>\n",
+        ),
+        escape(&feed_url),
+        escape(&feed_url),
+        escape(&changelog_url),
+        updated
+    ));
+
+    for release in changelog::RELEASES {
+        let date = match release.date {
+            Some(date) => timestamp(date),
+            None => continue,
+        };
+        let url = format!(
+            "{}{}",
+            SITE,
+            Route::ChangelogRelease(release.version.into()).to_url()
+        );
+        let content: String = release
+            .entries
+            .iter()
+            .map(|e| {
+                let marker = if e.breaking { "<strong>Breaking:</strong> " } else { "" };
+                format!("<li>{}{}</li>", marker, e.text)
+            })
+            .collect();
+
+        xml.push_str("  <entry>\n");
+        xml.push_str(&format!("    <title>Seed {}</title>\n", escape(release.version)));
+        xml.push_str(&format!("    <id>{}</id>\n", escape(&url)));
+        xml.push_str(&format!("    <link href=\"{}\"/>\n", escape(&url)));
+        xml.push_str(&format!(
+            "    <published>{0}</published>\n    <updated>{0}</updated>\n",
+            date
+        ));
+        xml.push_str(&format!(
+            "    <content type=\"html\">{}</content>\n",
+            escape(&format!("<ul>{}</ul>", content))
+        ));
+        xml.push_str("  </entry>\n");
+    }
+    xml.push_str("</feed>\n");
+    xml
+}
+
+/// Each guide section's HTML, which the app fetches when the section's opened: where it's
+/// served, as in `book::content_url` but without the leading `/`, and the HTML.
+pub fn section_contents() -> Vec<(String, String)> {
+    book::VERSIONS
+        .iter()
+        .flat_map(|version| {
+            version.sections.iter().map(move |section| {
+                let url = book::content_url(version.name, section.slug);
+                (url.trim_start_matches('/').to_string(), (section.text)())
+            })
+        })
+        .collect()
+}
+
+/// The page the app shows at a route, before any interaction; including the section's
+/// content, which the app would fetch.
+fn render(path: String, route: Route) -> RenderedPage {
+    let mut model = Model::default();
+    model.show(route);
+
+    let section = book::VERSIONS[model.guide_version]
+        .sections
+        .iter()
+        .find(|s| s.slug == model.guide_page);
+    if let Some(section) = section {
+        model
+            .guide_content
+            .insert(model.content_url(), Content::Loaded((section.text)()));
+    }
+
+    let mut html = String::new();
+    write_html(&view(&model), &mut html);
+    RenderedPage {
+        path,
+        html,
+        head: Meta::of(&model).head(),
+    }
+}
+
+fn write_html(node: &Node<Msg>, html: &mut String) {
+    match node {
+        Node::Element(el) if el.tag.as_str() == RAW_TAG => {
+            for child in &el.children {
+                if let Node::Text(text) = child {
+                    html.push_str(&text.text);
+                }
+            }
+        }
+        Node::Element(el) => {
+            let tag = el.tag.as_str();
+            html.push('<');
+            html.push_str(tag);
+            for (name, value) in &el.attrs.vals {
+                match value {
+                    AtValue::Some(value) => {
+                        html.push_str(&format!(" {}=\"{}\"", name.as_str(), escape(value)))
+                    }
+                    AtValue::None => {
+                        html.push(' ');
+                        html.push_str(name.as_str());
+                    }
+                    AtValue::Ignored => (),
+                }
+            }
+            let style = el.style.to_string();
+            if !style.is_empty() {
+                html.push_str(&format!(" style=\"{}\"", escape(&style)));
+            }
+            html.push('>');
+
+            if VOID_ELEMENTS.contains(&tag) {
+                return;
+            }
+            for child in &el.children {
+                write_html(child, html);
+            }
+            html.push_str(&format!("</{}>", tag));
+        }
+        Node::Text(text) => html.push_str(&escape(&text.text)),
+        Node::Empty => (),
+    }
+}
+
+/// Escape text for HTML, including in attribute values.
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::Path};
+
+    use super::*;
+
+    /// The markup around a route's content, one tag per line: the page with an empty guide
+    /// section, since the sections' content is the Markdown's, not the layout's.
+    fn layout(route: Route) -> String {
+        let mut model = Model::default();
+        model.show(route);
+        model.guide_content.insert(model.content_url(), Content::Loaded(String::new()));
+
+        let mut html = String::new();
+        write_html(&view(&model), &mut html);
+        html.replace("><", ">\n<") + "\n"
+    }
+
+    #[test]
+    fn renders_every_page() {
+        for page in pages() {
+            assert!(
+                !page.html.contains("Page not found"),
+                "/{} rendered the not-found page",
+                page.path
+            );
+            assert!(!page.html.contains(RAW_TAG), "/{} has a raw placeholder", page.path);
+            assert_ne!(page.head, default_head(), "/{} has the default title", page.path);
+        }
+    }
+
+    #[test]
+    fn indexes_every_page_and_release() {
+        let sitemap = sitemap();
+        for page in pages() {
+            let url = format!("<loc>{}/{}</loc>", SITE, page.path);
+            assert!(sitemap.contains(&url), "the sitemap hasn't got /{}", page.path);
+        }
+        assert_eq!(feed().matches("<entry>").count(), changelog::RELEASES.len());
+    }
+
+    /// Compares each route's layout with its snapshot in `snapshots`. To write the snapshots
+    /// of new routes, or replace them after changing the layout on purpose, run with
+    /// `UPDATE_SNAPSHOTS=1`, and review their diff.
+    #[test]
+    fn matches_snapshots() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("snapshots");
+        let update = env::var_os("UPDATE_SNAPSHOTS").is_some();
+        if update {
+            fs::create_dir_all(&dir).unwrap();
+        }
+
+        let mut changed = Vec::new();
+        for route in routes() {
+            let name = format!("{}.html", route.path().join("__"));
+            let path = dir.join(&name);
+            let html = layout(route);
+
+            if update {
+                fs::write(&path, html).unwrap();
+            } else if fs::read_to_string(&path).ok().as_ref() != Some(&html) {
+                changed.push(name);
+            }
+        }
+        assert!(
+            changed.is_empty(),
+            "These pages' layouts are missing from, or don't match, their snapshots; if that's \
+             intended, run with UPDATE_SNAPSHOTS=1: {}",
+            changed.join(", ")
+        );
+    }
+}
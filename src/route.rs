@@ -0,0 +1,224 @@
+//! The site's routes. This is the only place URLs are parsed, or produced, so links and
+//! routing can't disagree.
+
+use seed::Url;
+
+/// Guide routes have an optional version of the guide, eg `0.4` in `/0.4/guide/view`; without
+/// one, they refer to the newest version.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Route {
+    /// The guide's current section.
+    Guide { version: Option<String> },
+    /// A guide section, by its slug, eg `view`; optionally scrolled to one of its headings,
+    /// by `id`.
+    GuideSection {
+        version: Option<String>,
+        slug: String,
+        fragment: Option<String>,
+    },
+    Changelog,
+    /// A single release's changelog entries, eg `0.4.2`.
+    ChangelogRelease(String),
+    /// A path that doesn't match anything, without its leading `/`.
+    NotFound(String),
+}
+
+/// Whether a path segment is a guide version, like `0.4`.
+fn is_version(segment: &str) -> bool {
+    segment.starts_with(|c: char| c.is_ascii_digit())
+        && segment.chars().all(|c| c.is_ascii_digit() || c == '.')
+}
+
+impl Route {
+    /// The top of a guide section.
+    pub fn guide_section(version: Option<&str>, slug: &str) -> Self {
+        Route::GuideSection {
+            version: version.map(String::from),
+            slug: slug.into(),
+            fragment: None,
+        }
+    }
+
+    pub fn from_url(url: &Url) -> Self {
+        // Ignore empty segments, eg from trailing slashes.
+        let mut path: Vec<&str> = url
+            .path
+            .iter()
+            .map(String::as_str)
+            .filter(|p| !p.is_empty())
+            .collect();
+
+        let version = match path.as_slice() {
+            [version, "guide", ..] if is_version(version) => Some(path.remove(0).to_string()),
+            _ => None,
+        };
+
+        match (path.as_slice(), version) {
+            ([], None) => Route::Guide { version: None },
+            (["guide"], version) => Route::Guide { version },
+            (["guide", slug], version) => Route::GuideSection {
+                version,
+                slug: (*slug).to_string(),
+                fragment: url
+                    .hash
+                    .as_ref()
+                    .map(|hash| hash.trim_start_matches('#').to_string())
+                    .filter(|hash| !hash.is_empty()),
+            },
+            (["changelog"], None) => Route::Changelog,
+            (["changelog", release], None) => Route::ChangelogRelease((*release).to_string()),
+            (_, version) => {
+                let mut path: Vec<String> = path.iter().map(|p| p.to_string()).collect();
+                if let Some(version) = version {
+                    path.insert(0, version);
+                }
+                Route::NotFound(path.join("/"))
+            }
+        }
+    }
+
+    /// The path segments, eg `["0.4", "guide", "view"]`.
+    pub fn path(&self) -> Vec<String> {
+        let guide_path = |version: &Option<String>| match version {
+            Some(version) => vec![version.clone(), "guide".into()],
+            None => vec!["guide".into()],
+        };
+
+        match self {
+            Route::Guide { version } => guide_path(version),
+            Route::GuideSection { version, slug, .. } => {
+                let mut path = guide_path(version);
+                path.push(slug.clone());
+                path
+            }
+            Route::Changelog => vec!["changelog".into()],
+            Route::ChangelogRelease(release) => vec!["changelog".into(), release.clone()],
+            Route::NotFound(path) => path.split('/').map(String::from).collect(),
+        }
+    }
+
+    /// The URL to use in links, eg `/guide/routing#the-basics`.
+    pub fn to_url(&self) -> String {
+        let url = "/".to_string() + &self.path().join("/");
+        match self {
+            Route::GuideSection {
+                fragment: Some(fragment),
+                ..
+            } => url + "#" + fragment,
+            _ => url,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{book::VERSIONS, changelog::RELEASES};
+
+    fn url(url: &str) -> Url {
+        let mut parts = url.splitn(2, '#');
+        let path = parts.next().unwrap();
+        let mut url = Url::new(path.split('/').filter(|p| !p.is_empty()).collect());
+        url.hash = parts.next().map(String::from);
+        url
+    }
+
+    fn round_trip(route: Route) {
+        assert_eq!(Route::from_url(&url(&route.to_url())), route);
+    }
+
+    #[test]
+    fn round_trips() {
+        round_trip(Route::Guide { version: None });
+        round_trip(Route::Changelog);
+        round_trip(Route::NotFound("does/not-exist".into()));
+        for release in RELEASES {
+            round_trip(Route::ChangelogRelease(release.version.into()));
+        }
+
+        for version in VERSIONS {
+            round_trip(Route::Guide {
+                version: Some(version.name.into()),
+            });
+            for section in version.sections {
+                round_trip(Route::guide_section(None, section.slug));
+                round_trip(Route::guide_section(Some(version.name), section.slug));
+                for heading in section.headings {
+                    round_trip(Route::GuideSection {
+                        version: Some(version.name.into()),
+                        slug: section.slug.into(),
+                        fragment: Some(heading.id.into()),
+                    });
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parses_urls() {
+        assert_eq!(Route::from_url(&url("/")), Route::Guide { version: None });
+        assert_eq!(Route::from_url(&url("/guide")), Route::Guide { version: None });
+        assert_eq!(Route::from_url(&url("/guide/")), Route::Guide { version: None });
+        assert_eq!(
+            Route::from_url(&url("/0.4/guide")),
+            Route::Guide {
+                version: Some("0.4".into())
+            }
+        );
+        assert_eq!(
+            Route::from_url(&url("/guide/routing")),
+            Route::guide_section(None, "routing")
+        );
+        assert_eq!(
+            Route::from_url(&url("/0.4/guide/routing")),
+            Route::guide_section(Some("0.4"), "routing")
+        );
+        assert_eq!(
+            Route::from_url(&url("/guide/routing#the-basics")),
+            Route::GuideSection {
+                version: None,
+                slug: "routing".into(),
+                fragment: Some("the-basics".into())
+            }
+        );
+        assert_eq!(Route::from_url(&url("/changelog")), Route::Changelog);
+        assert_eq!(
+            Route::from_url(&url("/changelog/0.4.2")),
+            Route::ChangelogRelease("0.4.2".into())
+        );
+        assert_eq!(
+            Route::from_url(&url("/guide/routing/extra")),
+            Route::NotFound("guide/routing/extra".into())
+        );
+        assert_eq!(
+            Route::from_url(&url("/0.4/changelog")),
+            Route::NotFound("0.4/changelog".into())
+        );
+        assert_eq!(Route::from_url(&url("/0.4")), Route::NotFound("0.4".into()));
+        assert_eq!(Route::from_url(&url("/blog")), Route::NotFound("blog".into()));
+    }
+
+    #[test]
+    fn produces_urls() {
+        assert_eq!(Route::Guide { version: None }.to_url(), "/guide");
+        assert_eq!(Route::guide_section(None, "view").to_url(), "/guide/view");
+        assert_eq!(
+            Route::guide_section(Some("0.4"), "view").to_url(),
+            "/0.4/guide/view"
+        );
+        assert_eq!(
+            Route::GuideSection {
+                version: None,
+                slug: "routing".into(),
+                fragment: Some("the-basics".into())
+            }
+            .to_url(),
+            "/guide/routing#the-basics"
+        );
+        assert_eq!(Route::Changelog.to_url(), "/changelog");
+        assert_eq!(
+            Route::ChangelogRelease("0.4.2".into()).to_url(),
+            "/changelog/0.4.2"
+        );
+    }
+}
@@ -0,0 +1,239 @@
+//! Full-text search over the guide. The inverted index is built at compile time by
+//! `build.rs`; here we rank sections against a query, and cut snippets from their text.
+
+mod tokenize;
+
+use crate::book::VERSIONS;
+pub use tokenize::tokenize;
+
+include!(concat!(env!("OUT_DIR"), "/search_index.rs"));
+
+/// How many results to show.
+const MAX_RESULTS: usize = 8;
+/// Roughly how many characters of context to show around the first match.
+const SNIPPET_BEFORE: usize = 40;
+const SNIPPET_LENGTH: usize = 160;
+
+#[derive(Clone, Debug)]
+pub struct SearchResult {
+    /// Index into the version's `sections`, and the model's `guide_sections`.
+    pub section: usize,
+    pub score: f32,
+    /// The snippet's text, split into pieces; `true` marks pieces matching the query.
+    pub snippet: Vec<(String, bool)>,
+}
+
+/// Whether a word matches one of the query's terms. Prefixes count, so results show up
+/// while the last word is still being typed.
+fn matches(word: &str, terms: &[String]) -> bool {
+    let word = word.to_lowercase();
+    terms.iter().any(|t| word.starts_with(t.as_str()))
+}
+
+/// Find the sections of a version of the guide (by index into `book::VERSIONS`) matching
+/// every term in the query, best first. Ranking is TF-IDF, with terms in titles and
+/// headings weighted higher when the index is built.
+pub fn search(query: &str, version: usize) -> Vec<SearchResult> {
+    let terms = tokenize(query);
+    if terms.is_empty() {
+        return Vec::new();
+    }
+    let sections = VERSIONS[version].sections;
+    let index = INDEXES[version];
+
+    let section_count = sections.len() as f32;
+    let mut scores = vec![0.; sections.len()];
+    // How many of the query's terms each section matched.
+    let mut matched = vec![0; sections.len()];
+
+    for term in &terms {
+        // The index is sorted, so every term with this prefix follows the first one.
+        let start = match index.binary_search_by(|(t, _)| (*t).cmp(term.as_str())) {
+            Ok(i) | Err(i) => i,
+        };
+        let mut term_scores = vec![0.; sections.len()];
+
+        for (indexed_term, postings) in index[start..]
+            .iter()
+            .take_while(|(t, _)| t.starts_with(term.as_str()))
+        {
+            // Exact matches beat prefix matches.
+            let weight = if indexed_term == term { 1. } else { 0.5 };
+            let idf = (1. + section_count / postings.len() as f32).ln();
+
+            for (section, count) in postings.iter() {
+                let tf = 1. + (*count as f32).ln();
+                term_scores[*section] += weight * tf * idf;
+            }
+        }
+
+        for (i, score) in term_scores.into_iter().enumerate() {
+            if score > 0. {
+                scores[i] += score;
+                matched[i] += 1;
+            }
+        }
+    }
+
+    let mut results: Vec<SearchResult> = scores
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| matched[*i] == terms.len())
+        .map(|(section, score)| SearchResult {
+            section,
+            score,
+            snippet: snippet(sections[section].plain_text, &terms),
+        })
+        .collect();
+
+    results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+    results.truncate(MAX_RESULTS);
+    results
+}
+
+/// The words in a text, with their byte offsets.
+fn words(text: &str) -> Vec<(usize, &str)> {
+    let mut words = Vec::new();
+    let mut start = None;
+
+    for (i, c) in text.char_indices() {
+        match (tokenize::is_term_char(c), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                words.push((s, &text[s..i]));
+                start = None;
+            }
+            _ => (),
+        }
+    }
+    if let Some(s) = start {
+        words.push((s, &text[s..]));
+    }
+    words
+}
+
+/// Cut an excerpt of the text around the first match, splitting out the matching words so
+/// they can be highlighted.
+fn snippet(text: &str, terms: &[String]) -> Vec<(String, bool)> {
+    let words = words(text);
+    let first_match = words
+        .iter()
+        .find(|(_, w)| matches(w, terms))
+        .map_or(0, |(i, _)| *i);
+
+    // Start and end the excerpt at word boundaries.
+    let start = words
+        .iter()
+        .map(|(i, _)| *i)
+        .find(|i| i + SNIPPET_BEFORE >= first_match)
+        .unwrap_or(0);
+    let end = words
+        .iter()
+        .map(|(i, w)| i + w.len())
+        .find(|end| *end >= start + SNIPPET_LENGTH)
+        .unwrap_or(text.len());
+
+    let mut pieces = Vec::new();
+    if start > 0 {
+        pieces.push(("…".to_string(), false));
+    }
+    let mut last = start;
+    for (i, word) in words.iter().filter(|(i, _)| *i >= start && *i < end) {
+        if matches(word, terms) {
+            if *i > last {
+                pieces.push((text[last..*i].to_string(), false));
+            }
+            pieces.push((word.to_string(), true));
+            last = i + word.len();
+        }
+    }
+    pieces.push((text[last..end].to_string(), false));
+    if end < text.len() {
+        pieces.push(("…".to_string(), false));
+    }
+    pieces
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slugs(results: &[SearchResult]) -> Vec<&'static str> {
+        results
+            .iter()
+            .map(|r| VERSIONS[0].sections[r.section].slug)
+            .collect()
+    }
+
+    #[test]
+    fn ranks_the_section_about_a_term_first() {
+        assert_eq!(slugs(&search("routing", 0))[0], "routing");
+        assert_eq!(slugs(&search("fetch", 0))[0], "http-requests-and-state");
+    }
+
+    #[test]
+    fn matches_prefixes() {
+        assert_eq!(slugs(&search("rout", 0))[0], "routing");
+        // Exact matches count for more than prefixes.
+        let prefix = search("routin", 0);
+        let exact = search("routing", 0);
+        assert_eq!(slugs(&prefix)[0], "routing");
+        assert!(exact[0].score > prefix[0].score);
+    }
+
+    #[test]
+    fn needs_every_term() {
+        let both = search("routing fetch", 0);
+        assert!(!both.is_empty());
+        assert!(both.len() <= search("routing", 0).len());
+        assert!(search("routing qwertyuiop", 0).is_empty());
+        assert!(search("the", 0).is_empty());
+    }
+
+    #[test]
+    fn ranks_best_first_and_limits_results() {
+        let results = search("seed", 0);
+        assert!(results.len() <= MAX_RESULTS);
+        assert!(results.windows(2).all(|pair| pair[0].score >= pair[1].score));
+    }
+
+    #[test]
+    fn splits_out_matches() {
+        let pieces = snippet("Seed apps use routing, and Routes.", &tokenize("rout"));
+        assert_eq!(
+            pieces,
+            vec![
+                ("Seed apps use ".to_string(), false),
+                ("routing".to_string(), true),
+                (", and ".to_string(), false),
+                ("Routes".to_string(), true),
+                (".".to_string(), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn cuts_long_text_at_word_boundaries() {
+        let text = format!("{} needle {}", "word ".repeat(40), "more ".repeat(60));
+        let pieces = snippet(&text, &tokenize("needle"));
+
+        assert_eq!(pieces.first().unwrap(), &("…".to_string(), false));
+        assert_eq!(pieces.last().unwrap(), &("…".to_string(), false));
+        assert!(pieces.contains(&("needle".to_string(), true)));
+        // Neither end splits a word.
+        let excerpt: String = pieces[1..pieces.len() - 1]
+            .iter()
+            .map(|(s, _)| s.as_str())
+            .collect();
+        assert!(excerpt.starts_with("word "), "{:?}", excerpt);
+        assert!(excerpt.ends_with("more"), "{:?}", excerpt);
+        assert!(excerpt.len() >= SNIPPET_LENGTH);
+    }
+
+    #[test]
+    fn starts_short_text_at_the_beginning() {
+        let pieces = snippet("Just a view.", &tokenize("view"));
+        assert_eq!(pieces[0], ("Just a ".to_string(), false));
+        assert_eq!(pieces.last().unwrap(), &(".".to_string(), false));
+    }
+}
@@ -0,0 +1,43 @@
+//! Splitting text into search terms. This is shared by `build.rs`, which builds the index,
+//! and the app, which looks queries up in it, so the two always agree.
+
+/// Words too common to be worth indexing.
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "for", "from", "if", "in", "is", "it", "of",
+    "on", "or", "so", "that", "the", "this", "to", "we", "with", "you", "your",
+];
+
+/// Whether a character can be part of a search term. Underscores are included, so
+/// identifiers like `push_route` stay whole.
+pub fn is_term_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Split text into lowercase terms, dropping stop words and single characters.
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c| !is_term_char(c))
+        .map(str::to_lowercase)
+        .filter(|w| w.chars().count() > 1 && !STOP_WORDS.contains(&w.as_str()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lowercases_and_splits_on_punctuation() {
+        assert_eq!(tokenize("Seed's App::build()"), vec!["seed", "app", "build"]);
+    }
+
+    #[test]
+    fn keeps_identifiers_whole() {
+        assert_eq!(tokenize("seed::push_route(url)"), vec!["seed", "push_route", "url"]);
+    }
+
+    #[test]
+    fn drops_stop_words_and_single_characters() {
+        assert_eq!(tokenize("The model is a struct, x"), vec!["model", "struct"]);
+        assert!(tokenize("  ").is_empty());
+    }
+}
@@ -0,0 +1,82 @@
+//! The site's colour theme. It's applied as a `data-theme` attribute on the root element,
+//! which `style.css` and `syntax_style.css` key their dark colours off, and is remembered
+//! in local storage. `index.html` applies the stored theme before the page first paints,
+//! the same way `apply` does.
+
+/// The local storage key the choice is kept under.
+const STORAGE_KEY: &str = "theme";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Theme {
+    Light,
+    Dark,
+    /// Follow the OS's setting, via `prefers-color-scheme`.
+    System,
+}
+
+impl Theme {
+    /// The theme the reader chose last time; `System` if they haven't.
+    pub fn stored() -> Self {
+        let stored = seed::window()
+            .local_storage()
+            .ok()
+            .and_then(|storage| storage)
+            .and_then(|storage| storage.get_item(STORAGE_KEY).ok().and_then(|t| t));
+
+        match stored.as_deref() {
+            Some("light") => Theme::Light,
+            Some("dark") => Theme::Dark,
+            _ => Theme::System,
+        }
+    }
+
+    pub fn store(self) {
+        if let Ok(Some(storage)) = seed::window().local_storage() {
+            storage.set_item(STORAGE_KEY, self.name()).ok();
+        }
+    }
+
+    /// Set the page's colours.
+    pub fn apply(self) {
+        let dark = match self {
+            Theme::Light => false,
+            Theme::Dark => true,
+            Theme::System => seed::window()
+                .match_media("(prefers-color-scheme: dark)")
+                .ok()
+                .and_then(|query| query)
+                .is_some_and(|query| query.matches()),
+        };
+
+        if let Some(root) = seed::document().document_element() {
+            root.set_attribute("data-theme", if dark { "dark" } else { "light" })
+                .ok();
+        }
+    }
+
+    /// The theme the header's toggle switches to.
+    pub fn next(self) -> Self {
+        match self {
+            Theme::Light => Theme::Dark,
+            Theme::Dark => Theme::System,
+            Theme::System => Theme::Light,
+        }
+    }
+
+    /// How it's stored, eg `dark`.
+    fn name(self) -> &'static str {
+        match self {
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+            Theme::System => "system",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Theme::Light => "Light",
+            Theme::Dark => "Dark",
+            Theme::System => "System",
+        }
+    }
+}
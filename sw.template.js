@@ -0,0 +1,56 @@
+// The service worker, for reading the site offline. The prerender binary (src/bin/prerender.rs)
+// writes it into dist, filling in VERSION, a hash of the precached files, and PRECACHE, every
+// page and the files they load. A new VERSION installs alongside the old one, and waits;
+// src/offline.rs shows a banner offering to reload, which tells it to take over.
+
+const VERSION = "__VERSION__";
+const PRECACHE = __PRECACHE__;
+
+const CACHE = "seed-" + VERSION;
+
+self.addEventListener("install", function (event) {
+    event.waitUntil(caches.open(CACHE).then(function (cache) {
+        return cache.addAll(PRECACHE);
+    }));
+});
+
+self.addEventListener("activate", function (event) {
+    event.waitUntil(caches.keys().then(function (keys) {
+        return Promise.all(keys
+            .filter(function (key) { return key.startsWith("seed-") && key !== CACHE; })
+            .map(function (key) { return caches.delete(key); }));
+    }).then(function () {
+        return self.clients.claim();
+    }));
+});
+
+// From the reload banner.
+self.addEventListener("message", function (event) {
+    if (event.data === "skip-waiting") {
+        self.skipWaiting();
+    }
+});
+
+// Serve precached files from the cache, and everything else from the network. Pages that
+// aren't precached fall back to the root page, whose app shows the route, or that it's
+// not found.
+self.addEventListener("fetch", function (event) {
+    var request = event.request;
+    if (request.method !== "GET" || new URL(request.url).origin !== self.location.origin) {
+        return;
+    }
+
+    event.respondWith(caches.open(CACHE).then(function (cache) {
+        return cache.match(request, { ignoreSearch: true }).then(function (cached) {
+            if (cached) {
+                return cached;
+            }
+            return fetch(request).catch(function (error) {
+                if (request.mode === "navigate") {
+                    return cache.match("/");
+                }
+                throw error;
+            });
+        });
+    }));
+});